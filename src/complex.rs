@@ -0,0 +1,165 @@
+use std::ops;
+
+use itertools::Itertools;
+use num_complex::Complex64;
+
+use crate::{Dimensions, ErrorKind, Matrix};
+
+/// A matrix of `Complex64` values, the complex counterpart of [`Matrix`].
+///
+/// Available with the `complex` feature.
+#[derive(Debug, Clone)]
+pub struct ComplexMatrix {
+    buffer: Vec<Complex64>,
+    /// The dimensions of the matrix.
+    pub dimensions: Dimensions,
+}
+
+impl ComplexMatrix {
+    // Constructors
+
+    /// Creates an identity matrix of the given size.
+    pub fn identity(size: usize) -> Self {
+        let mut buffer = vec![Complex64::new(0.0, 0.0); size * size];
+        for index in 0..size {
+            buffer[index * size + index] = Complex64::new(1.0, 0.0);
+        }
+
+        Self {
+            buffer,
+            dimensions: Dimensions::Square(size),
+        }
+    }
+
+    // Element access
+
+    /// Returns the rows of the matrix as a vector of vectors.
+    pub fn rows(&self) -> Vec<Vec<Complex64>> {
+        let columns = self.dimensions.columns();
+        (0..self.dimensions.rows())
+            .map(|i| self.buffer[i * columns..(i + 1) * columns].to_vec())
+            .collect()
+    }
+
+    /// Returns an option containing a reference to the element at row `i` and column `j`.
+    ///
+    /// Returns `None` if indices are out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<&Complex64> {
+        if i >= self.dimensions.rows() || j >= self.dimensions.columns() {
+            return None;
+        }
+
+        self.buffer.get(i * self.dimensions.columns() + j)
+    }
+
+    // Manipulation
+
+    /// Returns a new matrix which is the transpose of the current matrix.
+    pub fn transposed(&self) -> Self {
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        let buffer = (0..columns)
+            .flat_map(|j| (0..rows).map(move |i| self.buffer[i * columns + j]))
+            .collect();
+
+        Self {
+            buffer,
+            dimensions: self.dimensions.transposed(),
+        }
+    }
+
+    /// Returns a new matrix with every element replaced by its complex conjugate.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            buffer: self.buffer.iter().map(|item| item.conj()).collect(),
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Returns the conjugate transpose (Hermitian adjoint) of the matrix.
+    pub fn adjoint(&self) -> Self {
+        self.transposed().conjugate()
+    }
+
+    // Properties
+
+    /// Returns `true` if the matrix is square.
+    pub fn is_square(&self) -> bool {
+        matches!(self.dimensions, Dimensions::Square(_))
+    }
+
+    /// Returns `true` if the matrix is equal to its own conjugate transpose.
+    pub fn is_hermitian(&self) -> bool {
+        self.is_square() && self == &self.adjoint()
+    }
+}
+
+impl TryFrom<Vec<Vec<Complex64>>> for ComplexMatrix {
+    type Error = ErrorKind;
+    fn try_from(collection: Vec<Vec<Complex64>>) -> Result<Self, Self::Error> {
+        if !collection.iter().map(|row| row.len()).all_equal() {
+            return Err(ErrorKind::DimensionsIncorrct(
+                "Row sizes should be equal.".to_string(),
+            ));
+        }
+
+        let columns = collection.first().map_or(0, |row| row.len());
+        let dimensions = Dimensions::from((collection.len(), columns));
+
+        Ok(Self {
+            buffer: collection.concat(),
+            dimensions,
+        })
+    }
+}
+
+/// Embeds a real matrix as a complex matrix with zero imaginary parts.
+impl From<Matrix> for ComplexMatrix {
+    fn from(matrix: Matrix) -> Self {
+        Self {
+            buffer: matrix
+                .buffer
+                .iter()
+                .map(|&item| Complex64::new(item, 0.0))
+                .collect(),
+            dimensions: matrix.dimensions,
+        }
+    }
+}
+
+impl ops::Mul for ComplexMatrix {
+    type Output = Option<Self>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        // The product is only defined when the inner dimensions match.
+        if self.dimensions.columns() != other.dimensions.rows() {
+            return None;
+        }
+
+        let (rows, columns) = (self.dimensions.rows(), other.dimensions.columns());
+        let other_columns = other.transposed().rows();
+
+        let buffer = self
+            .rows()
+            .iter()
+            .flat_map(|row| {
+                other_columns.iter().map(move |column| {
+                    row.iter()
+                        .zip(column.iter())
+                        .map(|(row_item, column_item)| row_item * column_item)
+                        .sum()
+                })
+            })
+            .collect();
+
+        Some(Self {
+            buffer,
+            dimensions: Dimensions::from((rows, columns)),
+        })
+    }
+}
+
+impl PartialEq for ComplexMatrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.buffer == other.buffer && self.dimensions == other.dimensions
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{Dimensions, Matrix, dot_product};
+
+/// Recursive least-squares estimator for the parameters `θ` of a linear model `y = xᵀθ`.
+///
+/// Each observation refines the estimate in `O(n²)` by updating the inverse covariance
+/// `P = (XᵀX)⁻¹` with a Sherman-Morrison rank-one update instead of re-solving the full system.
+#[derive(Debug, Clone)]
+pub struct RlsEstimator {
+    parameters: Vec<f64>,
+    inverse_covariance: Matrix,
+}
+
+impl RlsEstimator {
+    /// Creates an estimator for `size` parameters, starting from a zero estimate.
+    ///
+    /// The inverse covariance is initialized to `delta * I`; a large `delta` expresses little
+    /// confidence in the initial estimate, letting the first observations dominate.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::RlsEstimator;
+    ///
+    /// let mut estimator = RlsEstimator::new(2, 1000.0);
+    /// estimator.update(&[1.0, 2.0], 5.0);
+    /// ```
+    pub fn new(size: usize, delta: f64) -> Self {
+        Self {
+            parameters: vec![0.0; size],
+            inverse_covariance: Matrix::scalar(delta, size),
+        }
+    }
+
+    /// Applies one recursive least-squares step for the observation `y = xᵀθ`.
+    ///
+    /// Returns `true` if the estimate was updated, or `false` if `x` doesn't match the number
+    /// of parameters or the update is undefined.
+    pub fn update(&mut self, x: &[f64], y: f64) -> bool {
+        if x.len() != self.parameters.len() {
+            return false;
+        }
+
+        let Some(inverse_covariance) = Matrix::sherman_morrison(&self.inverse_covariance, x, x)
+        else {
+            return false;
+        };
+
+        let error = y - dot_product(x, &self.parameters);
+        for (parameter, row) in self
+            .parameters
+            .iter_mut()
+            .zip(inverse_covariance.rows().iter())
+        {
+            *parameter += dot_product(row, x) * error;
+        }
+        self.inverse_covariance = inverse_covariance;
+
+        true
+    }
+
+    /// Returns the current parameter estimate as a column matrix.
+    pub fn parameters(&self) -> Matrix {
+        Matrix {
+            buffer: self.parameters.clone(),
+            dimensions: Dimensions::from((self.parameters.len(), 1)),
+        }
+    }
+}
+
+/// Linear Kalman filter tracking a state estimate and its covariance.
+///
+/// The model is supplied at every step, so time-varying systems are supported.
+#[derive(Debug, Clone)]
+pub struct KalmanFilter {
+    /// The state estimate, an `n × 1` column matrix.
+    pub state: Matrix,
+    /// The `n × n` covariance of the state estimate.
+    pub covariance: Matrix,
+}
+
+impl KalmanFilter {
+    /// Creates a filter from an initial `n × 1` state estimate and its `n × n` covariance.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{KalmanFilter, Matrix, matrix};
+    ///
+    /// let filter = KalmanFilter::new(matrix![[0.0], [0.0]], Matrix::identity(2));
+    /// ```
+    pub fn new(state: Matrix, covariance: Matrix) -> Self {
+        Self { state, covariance }
+    }
+
+    /// Propagates the estimate through the transition model `f` with process noise covariance `q`:
+    /// `x = F x` and `P = F P Fᵀ + Q`.
+    ///
+    /// Returns `true` if the estimate was updated, or `false` if the dimensions don't match.
+    pub fn predict(&mut self, f: &Matrix, q: &Matrix) -> bool {
+        let prediction = (|| {
+            let state = f.try_mul(&self.state).ok()?;
+            let covariance = f
+                .try_mul(&self.covariance)
+                .ok()?
+                .try_mul(&f.transposed())
+                .ok()?
+                .checked_add(q)
+                .ok()?;
+
+            Some((state, covariance))
+        })();
+
+        match prediction {
+            Some((state, covariance)) => {
+                self.state = state;
+                self.covariance = covariance;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Corrects the estimate with the measurement `z` taken through the observation model `h`
+    /// with measurement noise covariance `r`.
+    ///
+    /// Computes the gain `K = P Hᵀ (H P Hᵀ + R)⁻¹`, then `x = x + K (z - H x)` and `P = (I - K H) P`.
+    ///
+    /// Returns `true` if the estimate was updated, or `false` if the dimensions don't match
+    /// or the innovation covariance is singular.
+    pub fn update(&mut self, h: &Matrix, r: &Matrix, z: &Matrix) -> bool {
+        let correction = (|| {
+            let innovation = z.checked_sub(&h.try_mul(&self.state).ok()?).ok()?;
+            let covariance_h_t = self.covariance.try_mul(&h.transposed()).ok()?;
+            let innovation_covariance = h.try_mul(&covariance_h_t).ok()?.checked_add(r).ok()?;
+            let gain = covariance_h_t
+                .try_mul(&innovation_covariance.inverse()?)
+                .ok()?;
+
+            let state = self
+                .state
+                .checked_add(&gain.try_mul(&innovation).ok()?)
+                .ok()?;
+            let covariance = Matrix::identity(self.state.dimensions.rows())
+                .checked_sub(&gain.try_mul(h).ok()?)
+                .ok()?
+                .try_mul(&self.covariance)
+                .ok()?;
+
+            Some((state, covariance))
+        })();
+
+        match correction {
+            Some((state, covariance)) => {
+                self.state = state;
+                self.covariance = covariance;
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Exact integer decompositions of integer-valued matrices.
+//!
+//! Elements are rounded to `i64` and transformed with unimodular row and column operations only,
+//! so no rounding error accumulates along the way.
+
+use itertools::Itertools;
+
+use crate::{Dimensions, EPSILON, Matrix};
+
+impl Matrix {
+    /// Returns the Smith normal form `D` of an integer-valued matrix along with unimodular `U`
+    /// and `V` such that `U A V = D`.
+    ///
+    /// `D` has the same dimensions as the matrix and is zero except for the invariant factors
+    /// `d₁, d₂, …` on its main diagonal, which are non-negative and each divide the next.
+    /// `U` is `m × m` and `V` is `n × n`, both integer matrices with determinant `±1`.
+    ///
+    /// Returns `None` if an element is not within `EPSILON` of an integer.
+    pub fn smith_normal_form(&self) -> Option<(Self, Self, Self)> {
+        if !self.is_integer_valued(EPSILON) {
+            return None;
+        }
+
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        let mut d = integer_rows(self);
+        let mut u = integer_identity(rows);
+        let mut v = integer_identity(columns);
+
+        'diagonal: for t in 0..rows.min(columns) {
+            loop {
+                // Move the smallest nonzero element of the trailing block to the pivot position.
+                let Some((i, j)) = (t..rows)
+                    .cartesian_product(t..columns)
+                    .filter(|&(i, j)| d[i][j] != 0)
+                    .min_by_key(|&(i, j)| d[i][j].abs())
+                else {
+                    break 'diagonal;
+                };
+                d.swap(t, i);
+                u.swap(t, i);
+                swap_columns(&mut d, t, j);
+                swap_columns(&mut v, t, j);
+
+                let pivot = d[t][t];
+                for i in t + 1..rows {
+                    let quotient = d[i][t] / pivot;
+                    add_row_multiple(&mut d, i, t, -quotient);
+                    add_row_multiple(&mut u, i, t, -quotient);
+                }
+                for j in t + 1..columns {
+                    let quotient = d[t][j] / pivot;
+                    add_column_multiple(&mut d, j, t, -quotient);
+                    add_column_multiple(&mut v, j, t, -quotient);
+                }
+
+                // Nonzero remainders are smaller than the pivot and become the next pivot.
+                if (t + 1..rows).any(|i| d[i][t] != 0) || (t + 1..columns).any(|j| d[t][j] != 0) {
+                    continue;
+                }
+
+                // The pivot must divide the rest of the block; otherwise fold the offending row in.
+                match (t + 1..rows).find(|&i| (t + 1..columns).any(|j| d[i][j] % pivot != 0)) {
+                    Some(i) => {
+                        add_row_multiple(&mut d, t, i, 1);
+                        add_row_multiple(&mut u, t, i, 1);
+                    }
+                    None => break,
+                }
+            }
+
+            if d[t][t] < 0 {
+                d[t].iter_mut().for_each(|item| *item = -*item);
+                u[t].iter_mut().for_each(|item| *item = -*item);
+            }
+        }
+
+        Some((
+            from_integer_rows(u, Dimensions::Square(rows)),
+            from_integer_rows(d, self.dimensions),
+            from_integer_rows(v, Dimensions::Square(columns)),
+        ))
+    }
+
+    /// Returns the row-style Hermite normal form `H = U A` of an integer-valued matrix, for a
+    /// unimodular `U`, computed with integer row operations.
+    ///
+    /// `H` is in row echelon form with zero rows at the bottom, each pivot is positive and the
+    /// elements above a pivot lie in `0..pivot`. The form is unique, so two integer matrices
+    /// generate the same row lattice exactly when their Hermite normal forms are equal.
+    ///
+    /// Returns `None` if an element is not within `EPSILON` of an integer.
+    pub fn hermite_normal_form(&self) -> Option<Self> {
+        if !self.is_integer_valued(EPSILON) {
+            return None;
+        }
+
+        let rows = self.dimensions.rows();
+        let mut h = integer_rows(self);
+        let mut pivot_row = 0;
+
+        for j in 0..self.dimensions.columns() {
+            if pivot_row == rows {
+                break;
+            }
+
+            // Euclid's algorithm down the column until a single nonzero element is left.
+            while let Some(i) = (pivot_row..rows)
+                .filter(|&i| h[i][j] != 0)
+                .min_by_key(|&i| h[i][j].abs())
+            {
+                h.swap(pivot_row, i);
+
+                for i in pivot_row + 1..rows {
+                    let quotient = h[i][j] / h[pivot_row][j];
+                    add_row_multiple(&mut h, i, pivot_row, -quotient);
+                }
+                if (pivot_row + 1..rows).all(|i| h[i][j] == 0) {
+                    break;
+                }
+            }
+
+            let pivot = h[pivot_row][j];
+            if pivot == 0 {
+                continue;
+            }
+            if pivot < 0 {
+                h[pivot_row].iter_mut().for_each(|item| *item = -*item);
+            }
+
+            let pivot = h[pivot_row][j];
+            for i in 0..pivot_row {
+                let quotient = h[i][j].div_euclid(pivot);
+                add_row_multiple(&mut h, i, pivot_row, -quotient);
+            }
+            pivot_row += 1;
+        }
+
+        Some(from_integer_rows(h, self.dimensions))
+    }
+}
+
+/// Rounds the elements of a matrix to integers, one vector per row.
+fn integer_rows(matrix: &Matrix) -> Vec<Vec<i64>> {
+    let columns = matrix.dimensions.columns();
+    (0..matrix.dimensions.rows())
+        .map(|i| {
+            matrix.buffer[i * columns..(i + 1) * columns]
+                .iter()
+                .map(|item| item.round() as i64)
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the rows of the `size × size` integer identity matrix.
+fn integer_identity(size: usize) -> Vec<Vec<i64>> {
+    (0..size)
+        .map(|i| (0..size).map(|j| i64::from(i == j)).collect())
+        .collect()
+}
+
+/// Builds a matrix from integer rows.
+fn from_integer_rows(rows: Vec<Vec<i64>>, dimensions: Dimensions) -> Matrix {
+    Matrix {
+        buffer: rows.concat().into_iter().map(|item| item as f64).collect(),
+        dimensions,
+    }
+}
+
+/// Adds `factor` times row `source` to row `target`.
+fn add_row_multiple(matrix: &mut [Vec<i64>], target: usize, source: usize, factor: i64) {
+    let source_row = matrix[source].clone();
+    for (item, source_item) in matrix[target].iter_mut().zip(source_row) {
+        *item += factor * source_item;
+    }
+}
+
+/// Adds `factor` times column `source` to column `target`.
+fn add_column_multiple(matrix: &mut [Vec<i64>], target: usize, source: usize, factor: i64) {
+    for row in matrix {
+        row[target] += factor * row[source];
+    }
+}
+
+/// Swaps columns `a` and `b`.
+fn swap_columns(matrix: &mut [Vec<i64>], a: usize, b: usize) {
+    for row in matrix {
+        row.swap(a, b);
+    }
+}
@@ -0,0 +1,55 @@
+//! Conversions between [`Matrix`] and the matrix types of other linear algebra crates.
+//!
+//! Each conversion is gated behind a feature of the same name as the foreign crate.
+
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+use crate::{Dimensions, Matrix};
+
+/// Converts into nalgebra's column-major storage, preserving element positions.
+#[cfg(feature = "nalgebra")]
+impl From<Matrix> for nalgebra::DMatrix<f64> {
+    fn from(matrix: Matrix) -> Self {
+        nalgebra::DMatrix::from_row_slice(
+            matrix.dimensions.rows(),
+            matrix.dimensions.columns(),
+            &matrix.buffer,
+        )
+    }
+}
+
+/// Converts from nalgebra's column-major storage, preserving element positions.
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::DMatrix<f64>> for Matrix {
+    fn from(matrix: nalgebra::DMatrix<f64>) -> Self {
+        let (rows, columns) = matrix.shape();
+
+        Matrix {
+            buffer: matrix.transpose().as_slice().to_vec(),
+            dimensions: Dimensions::from((rows, columns)),
+        }
+    }
+}
+
+/// Converts into a row-major `ndarray::Array2`, preserving shape and element order.
+#[cfg(feature = "ndarray")]
+impl From<Matrix> for ndarray::Array2<f64> {
+    fn from(matrix: Matrix) -> Self {
+        let shape = (matrix.dimensions.rows(), matrix.dimensions.columns());
+
+        // The buffer always holds exactly `rows * columns` elements.
+        ndarray::Array2::from_shape_vec(shape, matrix.buffer).unwrap()
+    }
+}
+
+/// Converts from an `ndarray::Array2` of any memory layout, preserving shape and element order.
+#[cfg(feature = "ndarray")]
+impl From<ndarray::Array2<f64>> for Matrix {
+    fn from(array: ndarray::Array2<f64>) -> Self {
+        let (rows, columns) = array.dim();
+
+        Matrix {
+            buffer: array.iter().copied().collect(),
+            dimensions: Dimensions::from((rows, columns)),
+        }
+    }
+}
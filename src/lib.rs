@@ -1,10 +1,48 @@
 #![allow(unused, renamed_and_removed_lints)]
 
+#[cfg(feature = "complex")]
+mod complex;
+mod estimation;
+mod integer;
+mod interop;
+mod scalar;
 mod tests;
+mod view;
 
 use std::{iter, ops, vec};
 
-use itertools::{Itertools, Product};
+use itertools::{Either, Itertools, Product};
+
+#[cfg(feature = "complex")]
+pub use complex::ComplexMatrix;
+pub use estimation::{KalmanFilter, RlsEstimator};
+pub use scalar::Scalar;
+pub use view::MatrixView;
+
+/// Magnitude below which a pivot is treated as zero by the elimination-based algorithms.
+const EPSILON: f64 = 1e-10;
+
+/// Size below which [`Matrix::strassen_mul`] multiplies blocks with the naive algorithm.
+const STRASSEN_THRESHOLD: usize = 64;
+
+/// Creates a [`Matrix`] from a literal list of rows.
+///
+/// # Panics
+/// Panics if the rows are not all of the same length.
+///
+/// # Example
+/// ```
+/// use matrix::matrix;
+///
+/// let m = matrix![[1.0, 2.0], [3.0, 4.0]];
+/// assert!(m.is_square());
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($([$($item:expr),* $(,)?]),* $(,)?) => {
+        $crate::Matrix::try_from(vec![$(vec![$($item),*]),*]).expect("Row sizes should be equal.")
+    };
+}
 
 /// Represents the dimensions of a matrix, either a square matrix or a rectangular matrix.
 ///
@@ -21,6 +59,18 @@ pub enum Dimensions {
 ///
 /// Provides construction, element access, manipulation, and common matrix properties and operations.
 impl Dimensions {
+    /// Creates the dimensions of a `len × len` square matrix.
+    pub fn square(len: usize) -> Dimensions {
+        Self::Square(len)
+    }
+
+    /// Creates the dimensions of a `rows × columns` matrix.
+    ///
+    /// Like `Dimensions::from((rows, columns))`, equal sides give the `Square` variant.
+    pub fn rectangle(rows: usize, columns: usize) -> Dimensions {
+        Self::from((rows, columns))
+    }
+
     /// Returns the number of rows in the dimensions.
     pub fn rows(&self) -> usize {
         *(match self {
@@ -90,20 +140,36 @@ impl PartialEq for Dimensions {
     }
 }
 
+/// A matrix of [`Scalar`] values, `f64` unless specified otherwise, stored in row-major order.
+///
+/// Structural operations are available for every element type, while the numeric algorithms
+/// are implemented for `Matrix<f64>`, also available as [`F64Matrix`].
+///
+/// # Operators
+/// - `+` and `-` panic if the dimensions differ; [`Matrix::checked_add`] and
+///   [`Matrix::checked_sub`] return an error instead.
+/// - `*` between matrices returns `None` if the inner dimensions differ; [`Matrix::try_mul`]
+///   returns an error describing both operands.
+/// - `/` by a scalar follows floating-point rules, so dividing by zero fills the matrix with
+///   infinities and `NaN`; [`Matrix::checked_div`] returns `ErrorKind::DividedByZero` instead.
+/// - `*` by a scalar and unary `-` never fail.
 #[derive(Debug, Clone)]
-pub struct Matrix {
-    buffer: Vec<f64>,
+pub struct Matrix<T = f64> {
+    buffer: Vec<T>,
     /// The dimensions of the matrix.
     pub dimensions: Dimensions,
 }
 
+/// A matrix of `f64` values, the element type used by the numeric algorithms.
+pub type F64Matrix = Matrix<f64>;
+
 #[derive(Debug)]
 pub enum ErrorKind {
     DimensionsIncorrct(String),
     DividedByZero,
 }
 
-impl Matrix {
+impl<T: Scalar> Matrix<T> {
     // Constructors
 
     /// Creates a new matrix filled with a constant `value` for the specified `dimensions`.
@@ -119,20 +185,39 @@ impl Matrix {
     ///
     /// let m = Matrix::constant(Dimensions::Square(3), 5.0);
     /// ```
-    pub fn constant(dimensions: Dimensions, value: f64) -> Self {
+    pub fn constant(dimensions: Dimensions, value: T) -> Self {
         Self {
-            buffer: iter::repeat(value)
-                .take(dimensions.count())
-                .collect::<Vec<f64>>(),
+            buffer: iter::repeat_n(value, dimensions.count()).collect::<Vec<T>>(),
             dimensions,
         }
     }
 
+    /// Creates a new matrix filled with `value` for the specified `dimensions`.
+    ///
+    /// Alias of [`Matrix::constant`].
+    pub fn with_value(dimensions: Dimensions, value: T) -> Self {
+        Self::constant(dimensions, value)
+    }
+
     /// Creates a zero matrix with the given dimensions.
     ///
     /// Equivalent to `Matrix::constant(dimensions, 0.0)`.
     pub fn zero(dimensions: Dimensions) -> Self {
-        Self::constant(dimensions, 0.0)
+        Self::constant(dimensions, T::ZERO)
+    }
+
+    /// Creates a matrix of ones with the given dimensions.
+    ///
+    /// Equivalent to `Matrix::constant(dimensions, 1.0)`.
+    pub fn ones(dimensions: Dimensions) -> Self {
+        Self::constant(dimensions, T::ONE)
+    }
+
+    /// Creates a new matrix filled with `value` for the specified `dimensions`.
+    ///
+    /// Alias of [`Matrix::constant`] following the numpy naming.
+    pub fn full(dimensions: Dimensions, value: T) -> Self {
+        Self::constant(dimensions, value)
     }
 
     /// Creates a diagonal matrix with specified values on the main diagonal.
@@ -145,7 +230,7 @@ impl Matrix {
     ///
     /// let diag = Matrix::diagonal(vec![1.0, 2.0, 3.0]);
     /// ```
-    pub fn diagonal(main_diagonal: Vec<f64>) -> Self {
+    pub fn diagonal(main_diagonal: Vec<T>) -> Self {
         let size = main_diagonal.len();
         let mut result: Self = Self::zero(size.into());
 
@@ -156,6 +241,137 @@ impl Matrix {
         result
     }
 
+    /// Creates a `size × size` matrix with `values` on the `k`-th diagonal and zeros elsewhere.
+    ///
+    /// `k = 0` is the main diagonal, `k > 0` a superdiagonal and `k < 0` a subdiagonal.
+    /// Returns `None` if `values.len()` differs from the length of that diagonal, `size - |k|`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let superdiagonal = Matrix::from_diag_k(vec![1.0, 2.0], 1, 3).unwrap();
+    /// ```
+    pub fn from_diag_k(values: Vec<T>, k: i32, size: usize) -> Option<Self> {
+        let offset = k.unsigned_abs() as usize;
+        if offset > size || values.len() != size - offset {
+            return None;
+        }
+
+        let mut result = Self::zero(size.into());
+
+        for (index, item) in values.into_iter().enumerate() {
+            if k >= 0 {
+                result.set(index, index + offset, item);
+            } else {
+                result.set(index + offset, index, item);
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Creates a block diagonal matrix with `blocks` placed along the main diagonal.
+    ///
+    /// The blocks may be rectangular; the result has as many rows and columns as all blocks
+    /// combined, with zeros outside the blocks. An empty `blocks` returns a 0×0 matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let m = Matrix::block_diagonal(vec![Matrix::identity(2), Matrix::scalar(3.0, 1)]);
+    /// ```
+    pub fn block_diagonal(blocks: Vec<Self>) -> Self {
+        let rows = blocks.iter().map(|block| block.dimensions.rows()).sum();
+        let columns = blocks.iter().map(|block| block.dimensions.columns()).sum();
+        let mut result = Self::zero(Dimensions::from((rows, columns)));
+
+        let (mut i, mut j) = (0, 0);
+        for block in &blocks {
+            result.set_block(i, j, block);
+            i += block.dimensions.rows();
+            j += block.dimensions.columns();
+        }
+
+        result
+    }
+
+    /// Concatenates matrices side by side.
+    ///
+    /// Returns `None` if `matrices` is empty or the matrices don't all have the same number of rows.
+    pub fn hstack(matrices: &[Self]) -> Option<Self> {
+        let rows = matrices.first()?.dimensions.rows();
+        if matrices
+            .iter()
+            .any(|matrix| matrix.dimensions.rows() != rows)
+        {
+            return None;
+        }
+
+        let columns = matrices
+            .iter()
+            .map(|matrix| matrix.dimensions.columns())
+            .sum();
+        let buffer = (0..rows)
+            .flat_map(|i| {
+                matrices.iter().flat_map(move |matrix| {
+                    let width = matrix.dimensions.columns();
+                    matrix.buffer[i * width..(i + 1) * width].iter().copied()
+                })
+            })
+            .collect();
+
+        Self::from_buffer(buffer, Dimensions::from((rows, columns))).ok()
+    }
+
+    /// Concatenates matrices on top of each other.
+    ///
+    /// Returns `None` if `matrices` is empty or the matrices don't all have the same number of columns.
+    pub fn vstack(matrices: &[Self]) -> Option<Self> {
+        let columns = matrices.first()?.dimensions.columns();
+        if matrices
+            .iter()
+            .any(|matrix| matrix.dimensions.columns() != columns)
+        {
+            return None;
+        }
+
+        let rows = matrices.iter().map(|matrix| matrix.dimensions.rows()).sum();
+        let buffer = matrices
+            .iter()
+            .flat_map(|matrix| matrix.buffer.iter().copied())
+            .collect();
+
+        Self::from_buffer(buffer, Dimensions::from((rows, columns))).ok()
+    }
+
+    /// Creates a matrix from a 2D layout of blocks, analogous to `numpy.block`.
+    ///
+    /// Each row of `layout` is stacked horizontally, then the resulting bands are stacked vertically.
+    /// Returns `None` if the blocks within a row have different heights, if the rows have
+    /// different total widths, or if the layout or any of its rows is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Dimensions, Matrix};
+    ///
+    /// let m: Matrix = Matrix::block(vec![
+    ///     vec![Matrix::identity(2), Matrix::zero(Dimensions::from((2, 1)))],
+    ///     vec![Matrix::zero(Dimensions::from((1, 2))), Matrix::identity(1)],
+    /// ])
+    /// .unwrap();
+    /// assert!(m.is_identity());
+    /// ```
+    pub fn block(layout: Vec<Vec<Self>>) -> Option<Self> {
+        let bands = layout
+            .iter()
+            .map(|row| Self::hstack(row))
+            .collect::<Option<Vec<_>>>()?;
+
+        Self::vstack(&bands)
+    }
+
     /// Creates an scalar matrix of the given size and value.
     ///
     /// An scalar matrix is a diagonal matrix with constant values on the main diagonal.
@@ -166,8 +382,8 @@ impl Matrix {
     ///
     /// let scalar = Matrix::scalar(0.5, 3);
     /// ```
-    pub fn scalar(value: f64, size: usize) -> Self {
-        Self::diagonal(iter::repeat(value).take(size).collect::<Vec<f64>>())
+    pub fn scalar(value: T, size: usize) -> Self {
+        Self::diagonal(iter::repeat_n(value, size).collect::<Vec<T>>())
     }
 
     /// Creates an identity matrix of the given size and value.
@@ -178,18 +394,82 @@ impl Matrix {
     /// ```
     /// use matrix::{Dimensions, Matrix};
     ///
-    /// let i3 = Matrix::identity(3);
+    /// let i3: Matrix = Matrix::identity(3);
+    /// let integer_i3 = Matrix::<i64>::identity(3);
     /// ```
     pub fn identity(size: usize) -> Self {
-        Self::diagonal(iter::repeat(1.0).take(size).collect::<Vec<f64>>())
+        Self::diagonal(iter::repeat_n(T::ONE, size).collect::<Vec<T>>())
+    }
+
+    /// Creates the exchange (reversal) matrix of the given size.
+    ///
+    /// The exchange matrix has ones on the secondary diagonal and zeros elsewhere.
+    /// Multiplying by it on the left reverses the row order, on the right the column order.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let j3: Matrix = Matrix::exchange(3);
+    /// ```
+    pub fn exchange(n: usize) -> Self {
+        let mut result = Self::zero(n.into());
+
+        for i in 0..n {
+            result.set(i, n - 1 - i, T::ONE);
+        }
+
+        result
     }
 
     // Element access
 
+    /// Returns the elements of the matrix as a row-major slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.buffer
+    }
+
+    /// Splits the elements into two mutable row-major slices, rows `0..at` and rows `at..`.
+    ///
+    /// The halves don't overlap, so they can be modified independently, for instance from
+    /// different threads. Returns `None` if `at` is greater than the number of rows.
+    pub fn split_rows_mut(&mut self, at: usize) -> Option<(&mut [T], &mut [T])> {
+        if at > self.dimensions.rows() {
+            return None;
+        }
+
+        Some(self.buffer.split_at_mut(at * self.dimensions.columns()))
+    }
+
+    /// Returns an iterator over mutable row-major slices of `rows_per_chunk` rows each.
+    ///
+    /// The last chunk holds fewer rows if `rows_per_chunk` doesn't divide the number of rows.
+    /// The chunks don't overlap, so they can be processed in parallel.
+    ///
+    /// # Panics
+    /// Panics if `rows_per_chunk` is zero.
+    pub fn chunks_rows_mut(&mut self, rows_per_chunk: usize) -> impl Iterator<Item = &mut [T]> {
+        assert!(rows_per_chunk > 0, "A chunk should hold at least one row.");
+
+        // A matrix without columns has an empty buffer, so any non-zero chunk size yields no chunks.
+        let chunk_size = (rows_per_chunk * self.dimensions.columns()).max(1);
+        self.buffer.chunks_mut(chunk_size)
+    }
+
+    /// Consumes the matrix, returning its elements in row-major order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.buffer
+    }
+
     /// Returns the rows of the matrix as a vector of vectors.
     ///
     /// Each inner vector represents one row.
-    pub fn rows(&self) -> Vec<Vec<f64>> {
+    pub fn rows(&self) -> Vec<Vec<T>> {
+        // `chunks_exact` can't produce empty chunks, so matrices without columns are handled apart.
+        if self.dimensions.columns() == 0 {
+            return vec![Vec::new(); self.dimensions.rows()];
+        }
+
         self.buffer
             .chunks_exact(self.dimensions.columns())
             .map(|row| row.to_owned())
@@ -201,7 +481,7 @@ impl Matrix {
     /// Returns the columns of the matrix as a vector of vectors.
     ///
     /// Each inner vector represents one column.
-    pub fn columns(&self) -> Vec<Vec<f64>> {
+    pub fn columns(&self) -> Vec<Vec<T>> {
         let columns = self.dimensions.columns();
         (0..columns)
             .map(|col_idx| {
@@ -218,7 +498,7 @@ impl Matrix {
     /// Returns an option containing a reference to the element at row `i` and column `j`.
     ///
     /// Returns `None` if indices are out of bounds.
-    pub fn get(&self, i: usize, j: usize) -> Option<&f64> {
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
         if (i >= self.dimensions.rows() || j >= self.dimensions.columns()) {
             return None;
         }
@@ -230,7 +510,7 @@ impl Matrix {
     /// Returns a vector containing all elements of the `n`th row.
     ///
     /// Returns `None` if `n` is out of range.
-    pub fn row(&self, n: usize) -> Option<Vec<f64>> {
+    pub fn row(&self, n: usize) -> Option<Vec<T>> {
         if n >= self.dimensions.rows() {
             return None;
         }
@@ -241,7 +521,7 @@ impl Matrix {
     /// Returns a vector containing all elements of the `n`th column.
     ///
     /// Returns `None` if `n` is out of range.
-    pub fn column(&self, n: usize) -> Option<Vec<f64>> {
+    pub fn column(&self, n: usize) -> Option<Vec<T>> {
         if n >= self.dimensions.columns() {
             return None;
         }
@@ -252,7 +532,7 @@ impl Matrix {
     /// Returns a vector of references to the elements on the main diagonal of a square matrix.
     ///
     /// Returns `None` if `the matrix is not square.
-    pub fn main_diagonal(&self) -> Option<Vec<&f64>> {
+    pub fn main_diagonal(&self) -> Option<Vec<&T>> {
         if !self.is_square() {
             return None;
         }
@@ -264,10 +544,18 @@ impl Matrix {
         )
     }
 
+    /// Returns the elements on the main diagonal of a square matrix.
+    ///
+    /// Owned counterpart of [`Matrix::main_diagonal`]. Returns `None` if the matrix is not square.
+    pub fn diagonal_values(&self) -> Option<Vec<T>> {
+        self.main_diagonal()
+            .map(|diagonal| diagonal.into_iter().copied().collect())
+    }
+
     /// Returns a vector of references to the elements on the secondary diagonal of a square matrix.
     ///
     /// Returns `None` if `the matrix is not square.
-    pub fn secondary_diagonal(&self) -> Option<Vec<&f64>> {
+    pub fn secondary_diagonal(&self) -> Option<Vec<&T>> {
         if !self.is_square() {
             return None;
         }
@@ -280,61 +568,19 @@ impl Matrix {
         )
     }
 
-    /// Returns the determinant of the matrix, calculated using an unoptimized algorithm.
-    ///
-    /// Returns `None` if `the matrix is not square.
-    pub fn determinant_unoptimized(&self) -> Option<f64> {
-        // checking for the matrix being square is done here and so any other checks are unnecessary.
-        if !self.is_square() {
-            return None;
-        }
-
-        Some(match self.dimensions.rows() {
-            0 => 0.0,
-            1 => *self.get(0, 0).unwrap(),
-            2 => {
-                self.main_diagonal()
-                    .unwrap()
-                    .iter()
-                    .fold(1f64, |value, &item| value * item)
-                    - self
-                        .secondary_diagonal()
-                        .unwrap()
-                        .iter()
-                        .fold(1f64, |value, &item| value * item)
-            }
-            dimensions => {
-                let r1: Vec<f64> = self.row(0).unwrap();
-                r1.iter()
-                    .enumerate()
-                    .map(|(index, value)| {
-                        let remaining_matrix = Matrix::from_buffer(
-                            self.buffer
-                                .iter()
-                                .enumerate()
-                                .skip(dimensions)
-                                .filter(|(i, _)| *i % dimensions != index)
-                                .map(|(_, item)| item.to_owned())
-                                .collect(),
-                            Dimensions::Square(dimensions - 1),
-                        )
-                        .unwrap();
-
-                        value
-                            * remaining_matrix.determinant_unoptimized().unwrap()
-                            * if index % 2 == 0 { 1.0 } else { -1.0 }
-                    })
-                    .sum()
-            }
-        })
-    }
-
     // Manipulation
 
     /// Transposes the matrix in place, swapping rows and columns.
+    ///
+    /// Square matrices are transposed by swapping elements across the main diagonal without allocating.
     pub fn transpose(&mut self) {
+        if self.is_square() {
+            self.transpose_square();
+            return;
+        }
+
         // Calculated Dimensions always match the element count.
-        *self = Matrix::from_buffer(self.columns().concat(), self.dimensions.transposed()).unwrap()
+        *self = Self::from_buffer(self.columns().concat(), self.dimensions.transposed()).unwrap()
     }
 
     /// Returns a new matrix which is the transpose of the current matrix.
@@ -345,117 +591,2196 @@ impl Matrix {
         result
     }
 
-    /// Sets the value at row `i` and column `j` to `value`.
+    /// Writes the transpose of the matrix into `out`, reusing its allocation.
     ///
-    /// Returns `true` if the value was updated, or `false` if indices were out of bounds.
-    pub fn set(&mut self, i: usize, j: usize, value: f64) -> bool {
-        if (i >= self.dimensions.rows() || j >= self.dimensions.columns()) {
-            return false;
+    /// Returns `ErrorKind::DimensionsIncorrct`, leaving `out` untouched, unless the dimensions
+    /// of `out` are `self.dimensions.transposed()`.
+    pub fn transpose_into(&self, out: &mut Self) -> Result<(), ErrorKind> {
+        if out.dimensions != self.dimensions.transposed() {
+            return Err(ErrorKind::DimensionsIncorrct(
+                "The output should have the transposed dimensions.".to_string(),
+            ));
         }
 
-        let index = (i * self.dimensions.columns()) + j;
-        *(self.buffer.get_mut(index).unwrap()) = value;
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        for (index, item) in out.buffer.iter_mut().enumerate() {
+            *item = self.buffer[(index % rows) * columns + index / rows];
+        }
 
-        true
+        Ok(())
     }
 
-    // Properties
+    /// Transposes the matrix in place across its anti-diagonal.
+    ///
+    /// Element `(i, j)` of an `m × n` matrix moves to `(n - 1 - j, m - 1 - i)`. This is the
+    /// transpose followed by a half-turn, which reverses the row-major buffer.
+    pub fn anti_transpose_inplace(&mut self) {
+        self.transpose();
+        self.buffer.reverse();
+    }
 
-    /// Returns `true` if this matrix has exactly the same dimensions as another.
-    pub fn is_same_size(&self, other: &Self) -> bool {
-        self.dimensions == other.dimensions
+    /// Returns a new matrix which is the transpose of the current matrix across its anti-diagonal.
+    pub fn anti_transpose(&self) -> Self {
+        let mut result = self.clone();
+        result.anti_transpose_inplace();
+
+        result
     }
 
-    /// Returns `true` if the matrix has only one column.
-    pub fn is_column(&self) -> bool {
-        self.dimensions.columns() == 1
+    /// Returns a copy of the matrix with the order of its rows reversed.
+    pub fn flip_vertical(&self) -> Self {
+        Self {
+            buffer: self.rows().into_iter().rev().flatten().collect(),
+            dimensions: self.dimensions,
+        }
     }
 
-    /// Returns `true` if the matrix has only one row.
-    pub fn is_row(&self) -> bool {
-        self.dimensions.rows() == 1
+    /// Returns a copy of the matrix with the order of the elements within each row reversed.
+    pub fn flip_horizontal(&self) -> Self {
+        Self {
+            buffer: self
+                .rows()
+                .into_iter()
+                .flat_map(|row| row.into_iter().rev())
+                .collect(),
+            dimensions: self.dimensions,
+        }
     }
 
-    /// Returns `true` if the matrix is square.
-    pub fn is_square(&self) -> bool {
-        match self.dimensions {
-            Dimensions::Square(_) => true,
-            _ => false,
+    /// Returns the matrix rotated a quarter turn clockwise; an `r × c` matrix becomes `c × r`.
+    pub fn rotate_90_cw(&self) -> Self {
+        self.transposed().flip_horizontal()
+    }
+
+    /// Returns the matrix rotated a quarter turn counterclockwise; an `r × c` matrix becomes `c × r`.
+    pub fn rotate_90_ccw(&self) -> Self {
+        self.transposed().flip_vertical()
+    }
+
+    /// Sets the value at row `i` and column `j` to `value`.
+    ///
+    /// Returns `true` if the value was updated, or `false` if indices were out of bounds.
+    pub fn set(&mut self, i: usize, j: usize, value: T) -> bool {
+        if (i >= self.dimensions.rows() || j >= self.dimensions.columns()) {
+            return false;
         }
+
+        let index = (i * self.dimensions.columns()) + j;
+        *(self.buffer.get_mut(index).unwrap()) = value;
+
+        true
     }
 
-    /// Returns `true` if the matrix is a scalar multiple of the identity matrix.
-    pub fn is_scalar(&self) -> bool {
-        // checking for the matrix being square is done here,
-        if !self.is_diagonal() {
+    /// Replaces the main diagonal of a square matrix with `values`.
+    ///
+    /// Returns `true` if the diagonal was updated, or `false` if the matrix is not square or
+    /// `values` doesn't have as many elements as the diagonal.
+    pub fn set_diagonal(&mut self, values: &[T]) -> bool {
+        let size = self.dimensions.rows();
+        if !self.is_square() || values.len() != size {
             return false;
         }
 
-        // so the .main_diagonal() function will always return Some.
-        match self.main_diagonal().unwrap().iter().all_equal_value() {
-            Ok(&value) => *value == 1.0,
-            _ => false,
+        for (item, value) in self.buffer.iter_mut().step_by(size + 1).zip(values) {
+            *item = *value;
         }
+
+        true
     }
 
-    /// Returns `true` if the matrix is upper triangular.
-    pub fn is_upper_triangular(&self) -> bool {
+    /// Sets every element on the main diagonal of a square matrix to `value`.
+    ///
+    /// Returns `true` if the diagonal was updated, or `false` if the matrix is not square.
+    pub fn fill_diagonal(&mut self, value: T) -> bool {
         if !self.is_square() {
             return false;
         }
 
         let size = self.dimensions.rows();
+        self.buffer
+            .iter_mut()
+            .step_by(size + 1)
+            .for_each(|item| *item = value);
 
-        self.rows()
-            .iter()
-            .rev()
-            .enumerate()
-            .flat_map(|(i, row)| row.iter().skip(size - i))
-            .all(|item| item == &0.0)
+        true
     }
 
-    /// Returns `true` if the matrix is lower triangular.
-    pub fn is_lower_triangular(&self) -> bool {
-        if !self.is_square() {
+    /// Replaces the `i`-th row with `values`.
+    ///
+    /// Returns `true` if the row was updated, or `false` if `i` is out of bounds or
+    /// `values` doesn't have as many elements as the matrix has columns.
+    pub fn set_row(&mut self, i: usize, values: &[T]) -> bool {
+        let columns = self.dimensions.columns();
+        if i >= self.dimensions.rows() || values.len() != columns {
             return false;
         }
 
-        self.rows()
-            .iter()
-            .enumerate()
-            .flat_map(|(i, row)| row.iter().skip(i + 1))
-            .all(|item| item == &0.0)
+        self.buffer[i * columns..(i + 1) * columns].copy_from_slice(values);
+
+        true
     }
 
-    /// Returns `true` if the matrix is diagonal.
-    pub fn is_diagonal(&self) -> bool {
-        if !self.is_square() {
+    /// Replaces the `j`-th column with `values`.
+    ///
+    /// Returns `true` if the column was updated, or `false` if `j` is out of bounds or
+    /// `values` doesn't have as many elements as the matrix has rows.
+    pub fn set_column(&mut self, j: usize, values: &[T]) -> bool {
+        let columns = self.dimensions.columns();
+        if j >= columns || values.len() != self.dimensions.rows() {
             return false;
         }
 
-        let divisor = self.dimensions.columns() + 1;
-        self.buffer
-            .iter()
+        for (item, value) in self.buffer.iter_mut().skip(j).step_by(columns).zip(values) {
+            *item = *value;
+        }
+
+        true
+    }
+
+    /// Inserts `values` as a new row at index `i`, shifting the following rows down.
+    ///
+    /// Returns `ErrorKind::DimensionsIncorrct` if `i` is greater than the number of rows or
+    /// `values` doesn't have as many elements as the matrix has columns.
+    pub fn insert_row(&mut self, i: usize, values: Vec<T>) -> Result<(), ErrorKind> {
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        if i > rows {
+            return Err(ErrorKind::DimensionsIncorrct(format!(
+                "Cannot insert row {i} into a matrix with {rows} rows."
+            )));
+        }
+        if values.len() != columns {
+            return Err(ErrorKind::DimensionsIncorrct(format!(
+                "Cannot insert a row of {} elements into a matrix with {columns} columns.",
+                values.len()
+            )));
+        }
+
+        self.buffer.splice(i * columns..i * columns, values);
+        self.dimensions = Dimensions::from((rows + 1, columns));
+
+        Ok(())
+    }
+
+    /// Inserts `values` as a new column at index `j`, shifting the following columns right.
+    ///
+    /// Returns `ErrorKind::DimensionsIncorrct` if `j` is greater than the number of columns or
+    /// `values` doesn't have as many elements as the matrix has rows.
+    pub fn insert_column(&mut self, j: usize, values: Vec<T>) -> Result<(), ErrorKind> {
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        if j > columns {
+            return Err(ErrorKind::DimensionsIncorrct(format!(
+                "Cannot insert column {j} into a matrix with {columns} columns."
+            )));
+        }
+        if values.len() != rows {
+            return Err(ErrorKind::DimensionsIncorrct(format!(
+                "Cannot insert a column of {} elements into a matrix with {rows} rows.",
+                values.len()
+            )));
+        }
+
+        let mut buffer = Vec::with_capacity(rows * (columns + 1));
+        for (i, value) in values.into_iter().enumerate() {
+            let row = &self.buffer[i * columns..(i + 1) * columns];
+            buffer.extend_from_slice(&row[..j]);
+            buffer.push(value);
+            buffer.extend_from_slice(&row[j..]);
+        }
+
+        self.buffer = buffer;
+        self.dimensions = Dimensions::from((rows, columns + 1));
+
+        Ok(())
+    }
+
+    /// Removes the `i`-th row, shifting the following rows up.
+    ///
+    /// Returns `true` if the row was removed, or `false` if `i` is out of bounds.
+    pub fn delete_row(&mut self, i: usize) -> bool {
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        if i >= rows {
+            return false;
+        }
+
+        self.buffer.drain(i * columns..(i + 1) * columns);
+        self.dimensions = Dimensions::from((rows - 1, columns));
+
+        true
+    }
+
+    /// Removes the `j`-th column, shifting the following columns left.
+    ///
+    /// Returns `true` if the column was removed, or `false` if `j` is out of bounds.
+    pub fn delete_column(&mut self, j: usize) -> bool {
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        if j >= columns {
+            return false;
+        }
+
+        let mut index = 0;
+        self.buffer.retain(|_| {
+            index += 1;
+            (index - 1) % columns != j
+        });
+        self.dimensions = Dimensions::from((rows, columns - 1));
+
+        true
+    }
+
+    /// Returns a new matrix with `f` applied to every element.
+    pub fn map(&self, f: impl FnMut(T) -> T) -> Self {
+        Self {
+            buffer: self.buffer.iter().copied().map(f).collect(),
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Applies `f` to every element in place, without allocating a new matrix.
+    pub fn apply(&mut self, mut f: impl FnMut(T) -> T) {
+        self.buffer.iter_mut().for_each(|item| *item = f(*item));
+    }
+
+    // Properties
+
+    /// Returns `true` if this matrix has exactly the same dimensions as another.
+    pub fn is_same_size(&self, other: &Self) -> bool {
+        self.dimensions == other.dimensions
+    }
+
+    /// Returns `true` if the matrix has only one column.
+    pub fn is_column(&self) -> bool {
+        self.dimensions.columns() == 1
+    }
+
+    /// Returns `true` if the matrix has only one row.
+    pub fn is_row(&self) -> bool {
+        self.dimensions.rows() == 1
+    }
+
+    /// Returns `true` if the matrix is square.
+    pub fn is_square(&self) -> bool {
+        matches!(self.dimensions, Dimensions::Square(_))
+    }
+
+    /// Returns `true` if the matrix is a scalar multiple of the identity matrix.
+    pub fn is_scalar(&self) -> bool {
+        // checking for the matrix being square is done here,
+        if !self.is_diagonal() {
+            return false;
+        }
+
+        // so the .main_diagonal() function will always return Some.
+        match self.main_diagonal().unwrap().iter().all_equal_value() {
+            Ok(&value) => *value == T::ONE,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the matrix is upper triangular.
+    pub fn is_upper_triangular(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        self.rows()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().take(i))
+            .all(|item| item == &T::ZERO)
+    }
+
+    /// Returns `true` if the matrix is lower triangular.
+    pub fn is_lower_triangular(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        self.rows()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().skip(i + 1))
+            .all(|item| item == &T::ZERO)
+    }
+
+    /// Returns `true` if the matrix is diagonal.
+    pub fn is_diagonal(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        let divisor = self.dimensions.columns() + 1;
+        self.buffer
+            .iter()
+            .enumerate()
+            .all(|(index, item)| index % divisor == 0 || item == &T::ZERO)
+    }
+
+    /// Returns `true` if the matrix is an identity matrix.
+    pub fn is_identity(&self) -> bool {
+        self == &Self::identity(self.dimensions.rows())
+    }
+
+    /// Returns `true` if every element satisfies the predicate; an empty matrix always does.
+    pub fn all(&self, mut predicate: impl FnMut(T) -> bool) -> bool {
+        self.buffer.iter().all(|&item| predicate(item))
+    }
+
+    // Linear algebra
+
+    /// Returns the element-wise sum `self + other`.
+    ///
+    /// Unlike the `+` operator, which panics, a dimension mismatch is reported as an error.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, ErrorKind> {
+        self.zip_with(other, |self_item, other_item| self_item + other_item)
+    }
+
+    /// Returns the element-wise difference `self - other`.
+    ///
+    /// Unlike the `-` operator, which panics, a dimension mismatch is reported as an error.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, ErrorKind> {
+        self.zip_with(other, |self_item, other_item| self_item - other_item)
+    }
+
+    /// Writes the element-wise sum `self + other` into `out`, reusing its allocation.
+    ///
+    /// Returns `ErrorKind::DimensionsIncorrct`, leaving `out` untouched, if the three matrices
+    /// don't all have the same dimensions.
+    pub fn add_into(&self, other: &Self, out: &mut Self) -> Result<(), ErrorKind> {
+        self.zip_with_into(other, out, |self_item, other_item| self_item + other_item)
+    }
+
+    /// Writes the element-wise difference `self - other` into `out`, reusing its allocation.
+    ///
+    /// Returns `ErrorKind::DimensionsIncorrct`, leaving `out` untouched, if the three matrices
+    /// don't all have the same dimensions.
+    pub fn sub_into(&self, other: &Self, out: &mut Self) -> Result<(), ErrorKind> {
+        self.zip_with_into(other, out, |self_item, other_item| self_item - other_item)
+    }
+
+    /// Returns the matrix product `self * other`.
+    ///
+    /// Unlike the `*` operator, the error describes both operands when the number of columns
+    /// of `self` doesn't match the number of rows of `other`.
+    pub fn try_mul(&self, other: &Self) -> Result<Self, ErrorKind> {
+        self.check_product(other)?;
+
+        let (self_rows, other_columns) = (self.dimensions.rows(), other.dimensions.columns());
+
+        // Materialize the rows and columns once instead of per element of the product.
+        let rows = self.rows();
+        let columns = other.columns();
+
+        let mut result_collection = Vec::with_capacity(self_rows * other_columns);
+
+        for row in &rows {
+            for column in &columns {
+                result_collection.push(dot_product(row, column));
+            }
+        }
+
+        Ok(Self {
+            buffer: result_collection,
+            dimensions: Dimensions::from((self_rows, other_columns)),
+        })
+    }
+
+    /// Writes the matrix product `self * other` into `out`, reusing its allocation.
+    ///
+    /// Returns `ErrorKind::DimensionsIncorrct`, leaving `out` untouched, if the inner dimensions
+    /// differ or `out` is not `self.rows × other.columns`.
+    pub fn mul_into(&self, other: &Self, out: &mut Self) -> Result<(), ErrorKind> {
+        self.check_product(other)?;
+
+        let (rows, inner, columns) = (
+            self.dimensions.rows(),
+            self.dimensions.columns(),
+            other.dimensions.columns(),
+        );
+        if out.dimensions != Dimensions::from((rows, columns)) {
+            return Err(ErrorKind::DimensionsIncorrct(format!(
+                "The output of a {rows}x{columns} product cannot be a {}x{} matrix.",
+                out.dimensions.rows(),
+                out.dimensions.columns(),
+            )));
+        }
+
+        for (index, item) in out.buffer.iter_mut().enumerate() {
+            let (i, j) = (index / columns, index % columns);
+            *item = self.buffer[i * inner..(i + 1) * inner]
+                .iter()
+                .zip(other.buffer.iter().skip(j).step_by(columns))
+                .map(|(&self_item, &other_item)| self_item * other_item)
+                .sum();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the direct sum `A ⊕ B`, the block diagonal matrix `[[A, 0], [0, B]]`.
+    ///
+    /// An `r₁ × c₁` and an `r₂ × c₂` matrix give an `(r₁ + r₂) × (c₁ + c₂)` result.
+    pub fn direct_sum(&self, other: &Self) -> Self {
+        Self::block_diagonal(vec![self.clone(), other.clone()])
+    }
+
+    /// Returns the trace, the sum of the elements on the main diagonal.
+    ///
+    /// Returns `None` if the matrix is not square.
+    pub fn trace(&self) -> Option<T> {
+        self.diagonal_values()
+            .map(|diagonal| diagonal.into_iter().sum())
+    }
+
+    /// Returns the matrix raised to the non-negative integer power `exponent`, computed by repeated squaring.
+    ///
+    /// `A^0` is the identity matrix. Returns `None` if the matrix is not square.
+    pub fn pow(&self, exponent: u32) -> Option<Self> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let mut result = Self::identity(self.dimensions.rows());
+        let mut base = self.clone();
+        let mut exponent = exponent;
+
+        // Square matrices of equal size can always be multiplied.
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = (result * base.clone()).unwrap();
+            }
+            base = (base.clone() * base).unwrap();
+            exponent /= 2;
+        }
+
+        Some(result)
+    }
+}
+
+impl Matrix {
+    // Constructors
+
+    /// Creates the 2×2 matrix rotating a vector counterclockwise by `angle` radians.
+    ///
+    /// The result is `[[cos θ, -sin θ], [sin θ, cos θ]]`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let quarter_turn = Matrix::rotation_2d(std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn rotation_2d(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            buffer: vec![cos, -sin, sin, cos],
+            dimensions: Dimensions::Square(2),
+        }
+    }
+
+    /// Creates the 3×3 matrix rotating a vector by `angle` radians about the x axis.
+    pub fn rotation_3d_x(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            buffer: vec![1.0, 0.0, 0.0, 0.0, cos, -sin, 0.0, sin, cos],
+            dimensions: Dimensions::Square(3),
+        }
+    }
+
+    /// Creates the 3×3 matrix rotating a vector by `angle` radians about the y axis.
+    pub fn rotation_3d_y(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            buffer: vec![cos, 0.0, sin, 0.0, 1.0, 0.0, -sin, 0.0, cos],
+            dimensions: Dimensions::Square(3),
+        }
+    }
+
+    /// Creates the 3×3 matrix rotating a vector by `angle` radians about the z axis.
+    pub fn rotation_3d_z(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            buffer: vec![cos, -sin, 0.0, sin, cos, 0.0, 0.0, 0.0, 1.0],
+            dimensions: Dimensions::Square(3),
+        }
+    }
+
+    /// Creates the companion matrix of the monic polynomial
+    /// `xⁿ + cₙ₋₁ xⁿ⁻¹ + … + c₁ x + c₀`, given its lower-order coefficients `[c₀, c₁, …, cₙ₋₁]`.
+    ///
+    /// The result is `n × n` with ones on the subdiagonal and `-c₀, …, -cₙ₋₁` down the last column,
+    /// so its characteristic polynomial, and therefore its eigenvalues, are those of the polynomial.
+    /// Returns `ErrorKind::DimensionsIncorrct` if `coeffs` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::{Matrix, matrix};
+    ///
+    /// // x² - 3x + 2 = (x - 1)(x - 2)
+    /// let companion = Matrix::companion(&[2.0, -3.0]).unwrap();
+    /// assert_eq!(companion, matrix![[0.0, -2.0], [1.0, 3.0]]);
+    /// ```
+    pub fn companion(coeffs: &[f64]) -> Result<Self, ErrorKind> {
+        if coeffs.is_empty() {
+            return Err(ErrorKind::DimensionsIncorrct(
+                "A companion matrix needs at least one coefficient.".to_string(),
+            ));
+        }
+
+        let size = coeffs.len();
+        let mut result = Self::zero(Dimensions::Square(size));
+        for (i, coefficient) in coeffs.iter().enumerate() {
+            if i > 0 {
+                result.buffer[i * size + i - 1] = 1.0;
+            }
+            result.buffer[i * size + size - 1] = -coefficient;
+        }
+
+        Ok(result)
+    }
+
+    /// Creates a `height × width` matrix from row-major grayscale pixels, mapping `0..=255` to `[0, 1]`.
+    ///
+    /// Returns `ErrorKind::DimensionsIncorrct` if `pixels.len()` is not `width * height`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let image = Matrix::from_grayscale(&[0, 255, 51, 102], 2, 2).unwrap();
+    /// assert_eq!(image.get(0, 1), Some(&1.0));
+    /// ```
+    pub fn from_grayscale(pixels: &[u8], width: usize, height: usize) -> Result<Self, ErrorKind> {
+        Self::from_buffer(
+            pixels.iter().map(|&pixel| pixel as f64 / 255.0).collect(),
+            Dimensions::from((height, width)),
+        )
+    }
+
+    // Element access
+
+    /// Formats the matrix with every element right-aligned in `width` characters and `precision`
+    /// decimal places, separated by a space, and a newline after each row.
+    ///
+    /// Columns line up across rows and across matrices formatted with the same arguments, as long
+    /// as no element needs more than `width` characters.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::matrix;
+    ///
+    /// let m = matrix![[1.0, -2.5], [10.0, 0.25]];
+    /// assert_eq!(m.to_fixed_string(6, 2), "  1.00  -2.50\n 10.00   0.25\n");
+    /// ```
+    pub fn to_fixed_string(&self, width: usize, precision: usize) -> String {
+        self.rows()
+            .iter()
+            .map(|row| {
+                let line = row
+                    .iter()
+                    .map(|item| format!("{item:>width$.precision$}"))
+                    .join(" ");
+                line + "\n"
+            })
+            .collect()
+    }
+
+    /// Formats the matrix with every element written as the closest fraction whose denominator is
+    /// at most `max_denominator`, separated by a space, and a newline after each row.
+    ///
+    /// The fractions are the continued-fraction convergents of the elements, so values like `0.5`
+    /// or `1.0 / 3.0` print as `1/2` and `1/3`, and integers print without a denominator.
+    /// Non-finite elements and elements beyond the range of `i64` are printed as they are.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::matrix;
+    ///
+    /// let m = matrix![[0.5, -1.0 / 3.0], [2.0, 0.75]];
+    /// assert_eq!(m.format_as_fractions(100), "1/2 -1/3\n2 3/4\n");
+    /// ```
+    pub fn format_as_fractions(&self, max_denominator: u64) -> String {
+        self.rows()
+            .iter()
+            .map(|row| {
+                let line = row
+                    .iter()
+                    .map(|&item| {
+                        if !item.is_finite() || item.abs() >= i64::MAX as f64 {
+                            return item.to_string();
+                        }
+                        match rational_approximation(item, max_denominator) {
+                            (numerator, 1) => numerator.to_string(),
+                            (numerator, denominator) => format!("{numerator}/{denominator}"),
+                        }
+                    })
+                    .join(" ");
+                line + "\n"
+            })
+            .collect()
+    }
+
+    /// Returns every element in row-major order as a `(numerator, denominator)` pair, the closest
+    /// fraction whose denominator is at most `max_denominator`.
+    ///
+    /// The fractions are the continued-fraction convergents of the elements and have a positive
+    /// denominator. `NaN` maps to `(0, 0)` and the infinities to `(±1, 0)`, while finite elements
+    /// beyond the range of `i64` saturate to `(i64::MAX, 1)` or `(i64::MIN, 1)`.
+    pub fn to_rational_pairs(&self, max_denominator: u64) -> Vec<(i64, i64)> {
+        self.buffer
+            .iter()
+            .map(|&item| rational_approximation(item, max_denominator))
+            .collect()
+    }
+
+    /// Converts the matrix to row-major grayscale pixels, mapping `[0, 1]` to `0..=255`.
+    ///
+    /// Elements are rounded to the nearest level and values outside `[0, 1]` are clamped.
+    pub fn to_grayscale(&self) -> Vec<u8> {
+        self.buffer
+            .iter()
+            .map(|item| (item * 255.0).round().clamp(0.0, 255.0) as u8)
+            .collect()
+    }
+
+    /// Returns a copy of the block spanning the given `rows` and `columns` ranges.
+    ///
+    /// Returns `None` if either range is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let m = Matrix::identity(4);
+    /// let top_left = m.submatrix(0..2, 0..2).unwrap();
+    /// ```
+    pub fn submatrix(&self, rows: ops::Range<usize>, columns: ops::Range<usize>) -> Option<Self> {
+        self.view(rows, columns).map(|view| view.to_owned())
+    }
+
+    /// Returns a view of the block spanning the given `rows` and `columns` ranges without copying it.
+    ///
+    /// Returns `None` if either range is out of bounds.
+    pub fn view(
+        &self,
+        rows: ops::Range<usize>,
+        columns: ops::Range<usize>,
+    ) -> Option<MatrixView<'_>> {
+        if rows.start > rows.end
+            || columns.start > columns.end
+            || rows.end > self.dimensions.rows()
+            || columns.end > self.dimensions.columns()
+        {
+            return None;
+        }
+
+        Some(MatrixView::new(self, rows, columns))
+    }
+
+    /// Returns the determinant of the matrix, calculated using an unoptimized algorithm.
+    ///
+    /// Returns `None` if `the matrix is not square.
+    pub fn determinant_unoptimized(&self) -> Option<f64> {
+        // checking for the matrix being square is done here and so any other checks are unnecessary.
+        if !self.is_square() {
+            return None;
+        }
+
+        Some(match self.dimensions.rows() {
+            0 => 0.0,
+            1 => *self.get(0, 0).unwrap(),
+            2 => {
+                self.main_diagonal()
+                    .unwrap()
+                    .iter()
+                    .fold(1f64, |value, &item| value * item)
+                    - self
+                        .secondary_diagonal()
+                        .unwrap()
+                        .iter()
+                        .fold(1f64, |value, &item| value * item)
+            }
+            dimensions => {
+                let r1: Vec<f64> = self.row(0).unwrap();
+                r1.iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let remaining_matrix = Matrix::from_buffer(
+                            self.buffer
+                                .iter()
+                                .enumerate()
+                                .skip(dimensions)
+                                .filter(|(i, _)| *i % dimensions != index)
+                                .map(|(_, item)| item.to_owned())
+                                .collect(),
+                            Dimensions::Square(dimensions - 1),
+                        )
+                        .unwrap();
+
+                        value
+                            * remaining_matrix.determinant_unoptimized().unwrap()
+                            * if index % 2 == 0 { 1.0 } else { -1.0 }
+                    })
+                    .sum()
+            }
+        })
+    }
+
+    /// Returns the determinant of the matrix, computed from its LU decomposition with partial pivoting.
+    ///
+    /// Returns `None` if the matrix is not square.
+    pub fn determinant(&self) -> Option<f64> {
+        let (factors, _, sign) = self.lu_factors()?;
+        let size = self.dimensions.rows();
+
+        Some(sign * (0..size).map(|i| factors[i * size + i]).product::<f64>())
+    }
+
+    /// Returns the determinants of `matrices`, in order, as computed by [`Matrix::determinant`].
+    ///
+    /// With the `rayon` feature enabled the determinants are computed in parallel.
+    pub fn determinants_batch(matrices: &[Matrix]) -> Vec<Option<f64>> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            matrices.par_iter().map(Matrix::determinant).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        matrices.iter().map(Matrix::determinant).collect()
+    }
+
+    /// Returns the determinant of the matrix, computed with the fraction-free Bareiss algorithm.
+    ///
+    /// Every intermediate division is exact for integer-valued matrices, so integer inputs of
+    /// moderate size produce exact integer determinants.
+    ///
+    /// Returns `None` if the matrix is not square.
+    pub fn determinant_bareiss(&self) -> Option<f64> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let size = self.dimensions.rows();
+        if size == 0 {
+            return Some(1.0);
+        }
+
+        let mut work = self.clone();
+        let mut sign = 1.0;
+        let mut previous_pivot = 1.0;
+
+        for k in 0..size - 1 {
+            if work.buffer[k * size + k] == 0.0 {
+                match (k + 1..size).find(|&i| work.buffer[i * size + k] != 0.0) {
+                    Some(i) => {
+                        work.swap_rows(k, i);
+                        sign = -sign;
+                    }
+                    None => return Some(0.0),
+                }
+            }
+
+            let pivot = work.buffer[k * size + k];
+            for i in k + 1..size {
+                for j in k + 1..size {
+                    work.buffer[i * size + j] = (work.buffer[i * size + j] * pivot
+                        - work.buffer[i * size + k] * work.buffer[k * size + j])
+                        / previous_pivot;
+                }
+            }
+            previous_pivot = pivot;
+        }
+
+        Some(sign * work.buffer[size * size - 1])
+    }
+
+    // Manipulation
+
+    /// Returns a copy of the matrix with the absolute value of every element.
+    pub fn abs(&self) -> Self {
+        self.map(f64::abs)
+    }
+
+    /// Replaces every element with its absolute value in place.
+    pub fn abs_inplace(&mut self) {
+        self.apply(f64::abs);
+    }
+
+    /// Returns a copy of the matrix with the square root of every element.
+    ///
+    /// Negative elements become `NaN`, following IEEE 754.
+    pub fn sqrt_elementwise(&self) -> Self {
+        self.map(f64::sqrt)
+    }
+
+    /// Returns a copy of the matrix with `e` raised to the power of every element.
+    ///
+    /// Large elements overflow to infinity, following IEEE 754.
+    pub fn exp_elementwise(&self) -> Self {
+        self.map(f64::exp)
+    }
+
+    /// Returns a copy of the matrix with the natural logarithm of every element.
+    ///
+    /// Zero elements become negative infinity and negative elements `NaN`, following IEEE 754.
+    pub fn ln_elementwise(&self) -> Self {
+        self.map(f64::ln)
+    }
+
+    /// Returns a copy of the matrix with every element raised to the power `exp`.
+    ///
+    /// Unlike [`Matrix::pow`], which multiplies the matrix by itself, every element is raised
+    /// independently with `f64::powf`. Negative elements with a fractional `exp` become `NaN`.
+    pub fn pow_elementwise(&self, exp: f64) -> Self {
+        self.map(|item| item.powf(exp))
+    }
+
+    /// Returns a copy of the matrix with every element rounded to the nearest integer.
+    ///
+    /// Useful to clean up rounding noise after computations that are exact in integers.
+    pub fn round_to_integers(&self) -> Self {
+        self.map(f64::round)
+    }
+
+    /// Returns a copy of the matrix with every element rounded to the nearest integer, ties away from zero.
+    ///
+    /// Alias of [`Matrix::round_to_integers`] following the naming of the other element-wise operations.
+    pub fn round_elementwise(&self) -> Self {
+        self.round_to_integers()
+    }
+
+    /// Returns a copy of the matrix with every element rounded toward negative infinity.
+    pub fn floor_elementwise(&self) -> Self {
+        self.map(f64::floor)
+    }
+
+    /// Returns a copy of the matrix with every element rounded toward positive infinity.
+    pub fn ceil_elementwise(&self) -> Self {
+        self.map(f64::ceil)
+    }
+
+    /// Returns a copy of the matrix with every element clamped to `[lo, hi]`.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi` or either bound is `NaN`, like `f64::clamp`.
+    pub fn clamp_elementwise(&self, lo: f64, hi: f64) -> Self {
+        self.map(|item| item.clamp(lo, hi))
+    }
+
+    /// Clamps every element to `[lo, hi]` in place.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi` or either bound is `NaN`, like `f64::clamp`.
+    pub fn clamp_inplace(&mut self, lo: f64, hi: f64) {
+        self.apply(|item| item.clamp(lo, hi));
+    }
+
+    /// Returns a copy of the matrix with every element below `lo` raised to `lo`.
+    ///
+    /// `clamp_min(0.0)` is the ReLU activation.
+    pub fn clamp_min(&self, lo: f64) -> Self {
+        self.map(|item| item.max(lo))
+    }
+
+    /// Returns a copy of the matrix with every element above `hi` lowered to `hi`.
+    pub fn clamp_max(&self, hi: f64) -> Self {
+        self.map(|item| item.min(hi))
+    }
+
+    /// Returns a matrix of the same dimensions with `1.0` where an element is `>= threshold` and
+    /// `0.0` elsewhere.
+    pub fn ge_mask(&self, threshold: f64) -> Self {
+        self.mask(|item| item >= threshold)
+    }
+
+    /// Returns a matrix of the same dimensions with `1.0` where an element is `> threshold` and
+    /// `0.0` elsewhere.
+    pub fn gt_mask(&self, threshold: f64) -> Self {
+        self.mask(|item| item > threshold)
+    }
+
+    /// Returns a matrix of the same dimensions with `1.0` where an element is `<= threshold` and
+    /// `0.0` elsewhere.
+    pub fn le_mask(&self, threshold: f64) -> Self {
+        self.mask(|item| item <= threshold)
+    }
+
+    /// Returns a matrix of the same dimensions with `1.0` where an element is `< threshold` and
+    /// `0.0` elsewhere.
+    pub fn lt_mask(&self, threshold: f64) -> Self {
+        self.mask(|item| item < threshold)
+    }
+
+    /// Returns a matrix of the same dimensions with `1.0` where an element is within `eps` of
+    /// `value` and `0.0` elsewhere.
+    pub fn eq_mask(&self, value: f64, eps: f64) -> Self {
+        self.mask(|item| (item - value).abs() <= eps)
+    }
+
+    /// Returns a copy of the matrix with every row divided by the greatest common divisor of its
+    /// entries, producing primitive integer rows.
+    ///
+    /// Elements are rounded to the nearest integer first. Rows of all zeros and rows with an
+    /// element beyond the range of `i64` are left unchanged after rounding.
+    pub fn reduce_rows_by_gcd(&self) -> Self {
+        let buffer = self
+            .round_to_integers()
+            .rows()
+            .into_iter()
+            .flat_map(|row| {
+                let divisor = if row.iter().any(|item| item.abs() >= i64::MAX as f64) {
+                    0
+                } else {
+                    row.iter().fold(0, |divisor, &item| {
+                        gcd(divisor, (item as i64).unsigned_abs())
+                    })
+                };
+                row.into_iter().map(move |item| {
+                    if divisor == 0 {
+                        item
+                    } else {
+                        item / divisor as f64
+                    }
+                })
+            })
+            .collect();
+
+        Self {
+            buffer,
+            dimensions: self.dimensions,
+        }
+    }
+
+    // Properties
+
+    /// Returns `true` if the matrix is a nonsingular M-matrix.
+    ///
+    /// The criterion used is the inverse-positivity characterization: the matrix is square,
+    /// every off-diagonal element is non-positive, and the matrix is invertible with an
+    /// element-wise nonnegative inverse (entries above `-1e-10` are accepted to absorb rounding).
+    pub fn is_m_matrix(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        let size = self.dimensions.rows();
+        let off_diagonal_non_positive = self
+            .buffer
+            .iter()
+            .enumerate()
+            .all(|(index, item)| index / size == index % size || *item <= 0.0);
+
+        off_diagonal_non_positive
+            && match self.inverse() {
+                Some(inverse) => inverse.buffer.iter().all(|item| *item >= -EPSILON),
+                None => false,
+            }
+    }
+
+    /// Returns `true` if both matrices have the same dimensions and every pair of
+    /// corresponding elements differs by at most `epsilon`.
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.is_same_size(other)
+            && self
+                .buffer
+                .iter()
+                .zip(other.buffer.iter())
+                .all(|(self_item, other_item)| (self_item - other_item).abs() <= epsilon)
+    }
+
+    /// Returns `true` if the matrix is square and `A * A ≈ A` within `eps`.
+    pub fn is_idempotent(&self, eps: f64) -> bool {
+        match self.pow(2) {
+            Some(square) => square.approx_eq(self, eps),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the matrix is square and `A * A ≈ I` within `eps`.
+    pub fn is_involutory(&self, eps: f64) -> bool {
+        match self.pow(2) {
+            Some(square) => square.approx_eq(&Self::identity(self.dimensions.rows()), eps),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the matrix is square and `A^k ≈ 0` within `eps` for some `1 <= k <= max_power`.
+    pub fn is_nilpotent(&self, max_power: u32, eps: f64) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        let zero = Self::zero(self.dimensions);
+        (1..=max_power).any(|k| self.pow(k).unwrap().approx_eq(&zero, eps))
+    }
+
+    /// Returns `true` if both matrices are square of the same size and their traces and
+    /// determinants agree within `eps`.
+    ///
+    /// Similar matrices always pass, but passing does not prove similarity: this is only a cheap
+    /// necessary check to rule out dissimilar pairs before a deeper analysis.
+    pub fn is_similar(&self, other: &Self, eps: f64) -> bool {
+        if !self.is_square() || !self.is_same_size(other) {
+            return false;
+        }
+
+        let close = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => (a - b).abs() <= eps,
+            _ => false,
+        };
+        close(self.trace(), other.trace()) && close(self.determinant(), other.determinant())
+    }
+
+    /// Returns `true` if the matrix is symmetric within `eps` and positive definite, that is its
+    /// Cholesky factorization succeeds with every pivot greater than `eps`.
+    ///
+    /// The pivot bound rejects singular positive semidefinite matrices whose pivots are only
+    /// positive because of rounding.
+    pub fn is_positive_definite(&self, eps: f64) -> bool {
+        self.is_square()
+            && self.approx_eq(&self.transposed(), eps)
+            && self.cholesky_factor(eps, |_| true).is_some()
+    }
+
+    /// Checks if every element is within `epsilon` of an integer.
+    pub fn is_integer_valued(&self, epsilon: f64) -> bool {
+        self.buffer
+            .iter()
+            .all(|item| (item - item.round()).abs() <= epsilon)
+    }
+
+    /// Returns `true` if every element is `>= 0`.
+    pub fn is_non_negative(&self) -> bool {
+        self.all(|item| item >= 0.0)
+    }
+
+    /// Returns `true` if every element is `> 0`.
+    pub fn is_strictly_positive(&self) -> bool {
+        self.all(|item| item > 0.0)
+    }
+
+    /// Returns `true` if every element is `<= 0`.
+    pub fn is_non_positive(&self) -> bool {
+        self.all(|item| item <= 0.0)
+    }
+
+    /// Returns `true` if every element is `< 0`.
+    pub fn is_strictly_negative(&self) -> bool {
+        self.all(|item| item < 0.0)
+    }
+
+    // Linear algebra
+
+    /// Writes `alpha * x + y` into `out`, reusing its allocation.
+    ///
+    /// Returns `ErrorKind::DimensionsIncorrct`, leaving `out` untouched, if `x`, `y` and `out`
+    /// don't all have the same dimensions.
+    pub fn axpy_into(alpha: f64, x: &Self, y: &Self, out: &mut Self) -> Result<(), ErrorKind> {
+        x.zip_with_into(y, out, |x_item, y_item| alpha * x_item + y_item)
+    }
+
+    /// Returns the matrix with every element divided by `rhs`.
+    ///
+    /// Unlike the `/` operator, which yields infinities and `NaN`, dividing by zero is reported
+    /// as `ErrorKind::DividedByZero`.
+    pub fn checked_div(&self, rhs: f64) -> Result<Self, ErrorKind> {
+        if rhs == 0.0 {
+            return Err(ErrorKind::DividedByZero);
+        }
+
+        Ok(self.clone() / rhs)
+    }
+
+    /// Returns the matrix product `self * other`, computed with Strassen's algorithm.
+    ///
+    /// The operands are recursively split into quadrants, multiplying them with 7 instead of 8
+    /// half-size products, for `O(n^2.807)` operations. Operands with an odd dimension are
+    /// zero-padded to even dimensions before splitting, and products with any dimension smaller
+    /// than 64 are computed with the naive algorithm, since the recursion only pays off for large
+    /// matrices. The rounding differs slightly from the `*` operator. Returns `None` if the number
+    /// of columns of `self` doesn't match the number of rows of `other`.
+    pub fn strassen_mul(&self, other: &Self) -> Option<Self> {
+        self.check_product(other).ok()?;
+
+        Some(self.strassen_product(other))
+    }
+
+    /// Returns the matrix product `self * other`, computing the rows of the result in parallel.
+    ///
+    /// Gives the same result as the `*` operator; the work is split across the rayon thread pool,
+    /// which pays off for large products. Returns `None` if the number of columns of `self`
+    /// doesn't match the number of rows of `other`.
+    #[cfg(feature = "parallel")]
+    pub fn par_mul(&self, other: &Self) -> Option<Self> {
+        use rayon::prelude::*;
+
+        self.check_product(other).ok()?;
+
+        let (rows, inner, columns) = (
+            self.dimensions.rows(),
+            self.dimensions.columns(),
+            other.dimensions.columns(),
+        );
+        let other_columns = other.columns();
+        let mut result = Self::zero(Dimensions::from((rows, columns)));
+
+        // A product without columns has an empty buffer, so any non-zero chunk size yields no rows.
+        result
+            .buffer
+            .par_chunks_mut(columns.max(1))
+            .enumerate()
+            .for_each(|(i, row)| {
+                let self_row = &self.buffer[i * inner..(i + 1) * inner];
+                for (item, column) in row.iter_mut().zip(&other_columns) {
+                    *item = dot_product(self_row, column);
+                }
+            });
+
+        Some(result)
+    }
+
+    /// Returns the row echelon form of the matrix, computed by Gaussian elimination with partial pivoting.
+    ///
+    /// Candidate pivots smaller in magnitude than a fixed tolerance are treated as zero, and the
+    /// entries eliminated below each pivot are set to exactly `0.0`.
+    pub fn row_echelon_form(&self) -> Self {
+        self.echelon().0
+    }
+
+    /// Returns the reduced row echelon form of the matrix.
+    ///
+    /// Starting from [`Matrix::row_echelon_form`], each pivot is scaled to `1.0` and the entries
+    /// above it are cleared. Pivots smaller in magnitude than a fixed tolerance are treated as zero.
+    pub fn rref(&self) -> Self {
+        let (mut work, pivot_columns) = self.echelon();
+        let columns = self.dimensions.columns();
+
+        for (pivot, &column) in pivot_columns.iter().enumerate().rev() {
+            let pivot_value = work.buffer[pivot * columns + column];
+            for j in column..columns {
+                work.buffer[pivot * columns + j] /= pivot_value;
+            }
+            work.buffer[pivot * columns + column] = 1.0;
+
+            for i in 0..pivot {
+                let factor = work.buffer[i * columns + column];
+                for j in column..columns {
+                    work.buffer[i * columns + j] -= factor * work.buffer[pivot * columns + j];
+                }
+                work.buffer[i * columns + column] = 0.0;
+            }
+        }
+
+        work
+    }
+
+    /// Returns the Frobenius inner product `Σ a_ij * b_ij` of two matrices.
+    ///
+    /// Returns `None` if the matrices don't have the same dimensions.
+    pub fn frobenius_inner(&self, other: &Self) -> Option<f64> {
+        if !self.is_same_size(other) {
+            return None;
+        }
+
+        Some(dot_product(&self.buffer, &other.buffer))
+    }
+
+    /// Returns the trace of the product `self * other` without forming the product.
+    ///
+    /// Computed as the sum of the dot products of the `i`-th row of `self` with the `i`-th column of `other`.
+    /// Returns `None` unless `self` is `m × n` and `other` is `n × m`.
+    pub fn trace_of_product(&self, other: &Self) -> Option<f64> {
+        if self.dimensions != other.dimensions.transposed() {
+            return None;
+        }
+
+        let columns = other.dimensions.columns();
+        Some(
+            self.rows()
+                .iter()
+                .enumerate()
+                .map(|(i, row)| {
+                    row.iter()
+                        .zip(other.buffer.iter().skip(i).step_by(columns))
+                        .map(|(row_item, column_item)| row_item * column_item)
+                        .sum::<f64>()
+                })
+                .sum(),
+        )
+    }
+
+    /// Returns the Frobenius norm, the square root of the sum of the squares of all elements.
+    pub fn frobenius_norm(&self) -> f64 {
+        dot_product(&self.buffer, &self.buffer).sqrt()
+    }
+
+    /// Returns the infinity norm, the maximum over the rows of the sum of absolute values.
+    ///
+    /// This is the matrix norm induced by the vector maximum norm. A matrix without elements has norm 0.
+    pub fn infinity_norm(&self) -> f64 {
+        self.rows()
+            .iter()
+            .map(|row| row.iter().map(|item| item.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the 1-norm, the maximum over the columns of the sum of absolute values.
+    ///
+    /// This is the matrix norm induced by the vector L1 norm. A matrix without elements has norm 0.
+    pub fn one_norm(&self) -> f64 {
+        let columns = self.dimensions.columns();
+
+        // Columns are strided in the row-major buffer, so accumulate every sum first.
+        let mut sums = vec![0.0; columns];
+        for (index, item) in self.buffer.iter().enumerate() {
+            sums[index % columns] += item.abs();
+        }
+
+        sums.into_iter().fold(0.0, f64::max)
+    }
+
+    /// Returns a copy of the matrix with every row divided by its Euclidean norm.
+    ///
+    /// Rows of all zeros are left unchanged, as dividing them by their zero norm would fill them with `NaN`.
+    pub fn normalize_rows(&self) -> Self {
+        let buffer = self
+            .rows()
+            .into_iter()
+            .flat_map(|row| {
+                let norm = dot_product(&row, &row).sqrt();
+                row.into_iter()
+                    .map(move |item| if norm == 0.0 { item } else { item / norm })
+            })
+            .collect();
+
+        Self {
+            buffer,
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Returns a copy of the matrix with every column divided by its Euclidean norm.
+    ///
+    /// Columns of all zeros are left unchanged, as dividing them by their zero norm would fill them with `NaN`.
+    pub fn normalize_columns(&self) -> Self {
+        let columns = self.dimensions.columns();
+
+        // Columns are strided in the row-major buffer, so accumulate every norm first.
+        let mut norms = vec![0.0; columns];
+        for (index, item) in self.buffer.iter().enumerate() {
+            norms[index % columns] += item * item;
+        }
+        norms.iter_mut().for_each(|norm| *norm = norm.sqrt());
+
+        let buffer = self
+            .buffer
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let norm = norms[index % columns];
+                if norm == 0.0 { *item } else { item / norm }
+            })
+            .collect();
+
+        Self {
+            buffer,
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Returns the LU decomposition `P A = L U` computed with partial pivoting.
+    ///
+    /// The result is `(L, U, permutation)` where `L` is unit lower triangular, `U` is upper triangular
+    /// and row `i` of `P A` is row `permutation[i]` of `A`.
+    ///
+    /// Returns `None` if the matrix is not square or is singular.
+    pub fn lu_decomposition(&self) -> Option<(Self, Self, Vec<usize>)> {
+        let (factors, permutation, _) = self.lu_factors()?;
+        let size = self.dimensions.rows();
+        if (0..size).any(|i| factors[i * size + i].abs() < EPSILON) {
+            return None;
+        }
+
+        let mut lower = Self::identity(size);
+        let mut upper = Self::zero(self.dimensions);
+        for (index, item) in factors.into_iter().enumerate() {
+            if index % size < index / size {
+                lower.buffer[index] = item;
+            } else {
+                upper.buffer[index] = item;
+            }
+        }
+
+        Some((lower, upper, permutation))
+    }
+
+    /// Solves `L x = b` for a lower triangular matrix `L` by forward substitution in `O(n²)`.
+    ///
+    /// Computes `x[i] = (b[i] - Σ_{j<i} L[i][j] x[j]) / L[i][i]` for increasing `i`.
+    ///
+    /// Returns `None` if the matrix is not square or not lower triangular, has a zero on its
+    /// diagonal, or `b` doesn't have as many elements as the matrix has rows.
+    pub fn forward_substitute(&self, b: &[f64]) -> Option<Vec<f64>> {
+        let size = self.dimensions.rows();
+        if !self.is_lower_triangular() || b.len() != size {
+            return None;
+        }
+        if (0..size).any(|i| self.buffer[i * size + i] == 0.0) {
+            return None;
+        }
+
+        let mut x = vec![0.0; size];
+        for i in 0..size {
+            let sum = dot_product(&self.buffer[i * size..i * size + i], &x[..i]);
+            x[i] = (b[i] - sum) / self.buffer[i * size + i];
+        }
+
+        Some(x)
+    }
+
+    /// Solves `U x = b` for an upper triangular matrix `U` by backward substitution in `O(n²)`.
+    ///
+    /// Computes `x[i] = (b[i] - Σ_{j>i} U[i][j] x[j]) / U[i][i]` for decreasing `i`.
+    ///
+    /// Returns `None` if the matrix is not square or not upper triangular, has a zero on its
+    /// diagonal, or `b` doesn't have as many elements as the matrix has rows.
+    pub fn backward_substitute(&self, b: &[f64]) -> Option<Vec<f64>> {
+        let size = self.dimensions.rows();
+        if !self.is_upper_triangular() || b.len() != size {
+            return None;
+        }
+        if (0..size).any(|i| self.buffer[i * size + i] == 0.0) {
+            return None;
+        }
+
+        let mut x = vec![0.0; size];
+        for i in (0..size).rev() {
+            let sum = dot_product(&self.buffer[i * size + i + 1..(i + 1) * size], &x[i + 1..]);
+            x[i] = (b[i] - sum) / self.buffer[i * size + i];
+        }
+
+        Some(x)
+    }
+
+    /// Solves the linear system `A x = b` using the LU decomposition of `A`.
+    ///
+    /// `b` may have several columns, in which case each column is solved for and the solutions
+    /// are returned as the corresponding columns of the result.
+    ///
+    /// Returns `None` if the matrix is not square, `b` doesn't have as many rows as the matrix,
+    /// or the matrix is singular.
+    pub fn solve(&self, b: &Matrix) -> Option<Self> {
+        if b.dimensions.rows() != self.dimensions.rows() {
+            return None;
+        }
+
+        let (lower, upper, permutation) = self.lu_decomposition()?;
+        let solutions = b
+            .columns()
+            .iter()
+            .map(|column| {
+                let permuted = permutation.iter().map(|&i| column[i]).collect_vec();
+                upper.backward_substitute(&lower.forward_substitute(&permuted)?)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Self::from_columns(solutions, b.dimensions)
+    }
+
+    /// Orthonormalizes the columns of the matrix with the modified Gram-Schmidt process.
+    ///
+    /// Returns a matrix of the same dimensions whose columns form an orthonormal basis of the
+    /// column space, the `k`-th column spanning the same space as the first `k` original columns.
+    ///
+    /// Returns `None` if a column is (nearly) a linear combination of the earlier ones.
+    pub fn gram_schmidt(&self) -> Option<Self> {
+        self.qr_decomposition().map(|(q, _)| q)
+    }
+
+    /// Returns the thin QR decomposition `A = Q R` of a matrix with at least as many rows as columns,
+    /// computed with the modified Gram-Schmidt process.
+    ///
+    /// `Q` is `m × n` with orthonormal columns and `R` is `n × n` upper triangular with a positive diagonal.
+    ///
+    /// Returns `None` if the matrix has more columns than rows or its columns are linearly dependent.
+    pub fn qr_decomposition(&self) -> Option<(Self, Self)> {
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        if rows < columns {
+            return None;
+        }
+
+        let mut basis = self.columns();
+        let mut r = Self::zero(columns.into());
+
+        for k in 0..columns {
+            let norm = dot_product(&basis[k], &basis[k]).sqrt();
+            if norm < EPSILON {
+                return None;
+            }
+
+            r.buffer[k * columns + k] = norm;
+            basis[k].iter_mut().for_each(|item| *item /= norm);
+
+            let (done, remaining) = basis.split_at_mut(k + 1);
+            for (offset, column) in remaining.iter_mut().enumerate() {
+                let projection = dot_product(&done[k], column);
+                r.buffer[k * columns + k + 1 + offset] = projection;
+                for (item, basis_item) in column.iter_mut().zip(done[k].iter()) {
+                    *item -= projection * basis_item;
+                }
+            }
+        }
+
+        let q = Self::from_buffer(basis.concat(), Dimensions::from((columns, rows)))
+            .ok()?
+            .transposed();
+
+        Some((q, r))
+    }
+
+    /// Returns the least-squares solution `x` minimizing `||A x - b||²`, computed through the QR
+    /// decomposition of `A` by solving `R x = Qᵀ b`.
+    ///
+    /// `b` may have several columns; the result has as many rows as the matrix has columns.
+    ///
+    /// Returns `None` if `b` doesn't have as many rows as the matrix, or if the matrix has more
+    /// columns than rows or is rank deficient.
+    pub fn lstsq(&self, b: &Matrix) -> Option<Self> {
+        if b.dimensions.rows() != self.dimensions.rows() {
+            return None;
+        }
+
+        let (q, r) = self.qr_decomposition()?;
+        let projected = q.transposed().try_mul(b).ok()?;
+        let solutions = projected
+            .columns()
+            .iter()
+            .map(|column| r.backward_substitute(column))
+            .collect::<Option<Vec<_>>>()?;
+
+        Self::from_columns(solutions, projected.dimensions)
+    }
+
+    /// Applies the matrix as a 3×3 homogeneous transform to 2D points.
+    ///
+    /// Each point `(x, y)` is extended to `(x, y, 1)`, multiplied by the matrix and divided by
+    /// the resulting `w` component. Points mapped to `w = 0` end up at infinity.
+    /// Returns `None` if the matrix is not 3×3.
+    pub fn transform_points_2d(&self, points: &[(f64, f64)]) -> Option<Vec<(f64, f64)>> {
+        if self.dimensions != Dimensions::Square(3) {
+            return None;
+        }
+
+        Some(
+            points
+                .iter()
+                .map(|&(x, y)| {
+                    let transformed = self.mul_vector(&[x, y, 1.0]);
+                    (
+                        transformed[0] / transformed[2],
+                        transformed[1] / transformed[2],
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Composes transforms given in the order they are applied into a single matrix,
+    /// `Tₙ ⋯ T₂ T₁` for `transforms = [T₁, T₂, …, Tₙ]`.
+    ///
+    /// Applying the result to a vector is the same as applying each transform in turn.
+    /// Returns `None` if `transforms` is empty or the matrices are not all square of the same size.
+    pub fn compose(transforms: &[Matrix]) -> Option<Self> {
+        let (first, rest) = transforms.split_first()?;
+        if !first.is_square()
+            || rest
+                .iter()
+                .any(|transform| transform.dimensions != first.dimensions)
+        {
+            return None;
+        }
+
+        rest.iter().try_fold(first.clone(), |composed, transform| {
+            transform.clone() * composed
+        })
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse `A⁺` of a full-rank matrix.
+    ///
+    /// A matrix with at least as many rows as columns gets the left inverse `(AᵀA)⁻¹ Aᵀ`, so that
+    /// `A⁺ A = I`; a wider matrix gets the right inverse `Aᵀ (AAᵀ)⁻¹`, so that `A A⁺ = I`.
+    /// For a square matrix both equal the inverse.
+    ///
+    /// Returns `None` if the matrix is rank deficient.
+    pub fn pseudo_inverse(&self) -> Option<Self> {
+        let transposed = self.transposed();
+
+        if self.dimensions.rows() >= self.dimensions.columns() {
+            (transposed.clone() * self.clone())?.inverse()? * transposed
+        } else {
+            transposed.clone() * (self.clone() * transposed)?.inverse()?
+        }
+    }
+
+    /// Returns the orthogonal projector `A (AᵀA)⁻¹ Aᵀ` onto the column space of the matrix.
+    ///
+    /// The result is symmetric and idempotent. Returns `None` if `AᵀA` is singular, that is
+    /// if the columns of the matrix are linearly dependent.
+    pub fn projection(&self) -> Option<Self> {
+        let transposed = self.transposed();
+        let gram_inverse = (transposed.clone() * self.clone())?.inverse()?;
+
+        (self.clone() * gram_inverse)? * transposed
+    }
+
+    /// Returns the Cholesky factor `L` of a symmetric positive definite matrix, the lower
+    /// triangular matrix with a positive diagonal such that `L Lᵀ = A`.
+    ///
+    /// Only the lower triangle of the matrix is read. Returns `None` if the matrix is not square
+    /// or a non-positive pivot arises, which means it is not positive definite.
+    pub fn cholesky(&self) -> Option<Self> {
+        self.cholesky_factor(0.0, |_| true)
+    }
+
+    /// Returns the zero fill-in incomplete Cholesky factor `L` of a symmetric positive definite matrix.
+    ///
+    /// `L` is lower triangular and only has nonzeros where the lower triangle of the matrix does,
+    /// so `L Lᵀ` reproduces the matrix on its sparsity pattern. Elements with a magnitude of at
+    /// most `epsilon` are treated as structural zeros. The factor is typically used as a
+    /// preconditioner for iterative solvers.
+    ///
+    /// Returns `None` if the matrix is not square or a non-positive pivot arises.
+    pub fn incomplete_cholesky(&self, epsilon: f64) -> Option<Self> {
+        self.cholesky_factor(0.0, |item| item.abs() > epsilon)
+    }
+
+    /// Solves `A x = b` for a symmetric positive definite `A` with the preconditioned conjugate gradient method.
+    ///
+    /// `preconditioner` is a lower triangular factor `L` such as the one returned by
+    /// [`Matrix::incomplete_cholesky`]; each iteration applies `(L Lᵀ)⁻¹` through a forward and a
+    /// backward triangular solve. Passing the identity matrix yields the plain conjugate gradient method.
+    /// `b` must be a column matrix. Iteration stops once the Euclidean norm of the residual is at most `epsilon`.
+    ///
+    /// Returns `None` if the dimensions don't match, the preconditioner has a zero on its diagonal,
+    /// the iteration breaks down, or it doesn't converge within `iterations` steps.
+    pub fn pcg_solve(
+        &self,
+        b: &Matrix,
+        preconditioner: &Matrix,
+        iterations: usize,
+        epsilon: f64,
+    ) -> Option<Self> {
+        let size = self.dimensions.rows();
+        if !self.is_square()
+            || b.dimensions != Dimensions::from((size, 1))
+            || !preconditioner.is_same_size(self)
+        {
+            return None;
+        }
+
+        let mut x = vec![0.0; size];
+        let mut residual = b.buffer.clone();
+        let mut z = preconditioner.cholesky_factor_solve(&residual)?;
+        let mut direction = z.clone();
+        let mut residual_z = dot_product(&residual, &z);
+
+        for _ in 0..iterations {
+            if dot_product(&residual, &residual).sqrt() <= epsilon {
+                break;
+            }
+
+            let a_direction = self.mul_vector(&direction);
+            let curvature = dot_product(&direction, &a_direction);
+            if curvature <= 0.0 {
+                return None;
+            }
+
+            let alpha = residual_z / curvature;
+            for i in 0..size {
+                x[i] += alpha * direction[i];
+                residual[i] -= alpha * a_direction[i];
+            }
+
+            z = preconditioner.cholesky_factor_solve(&residual)?;
+            let next_residual_z = dot_product(&residual, &z);
+            let beta = next_residual_z / residual_z;
+            for i in 0..size {
+                direction[i] = z[i] + beta * direction[i];
+            }
+            residual_z = next_residual_z;
+        }
+
+        if dot_product(&residual, &residual).sqrt() > epsilon {
+            return None;
+        }
+
+        Self::from_buffer(x, b.dimensions).ok()
+    }
+
+    /// Runs `steps` iterations of the Arnoldi process starting from `q0`.
+    ///
+    /// Returns `(Q, H)` where the `k + 1` columns of the `n × (k + 1)` matrix `Q` form an orthonormal
+    /// basis of the Krylov subspace spanned by `q0, A q0, …, A^k q0`, and the `(k + 1) × k` upper
+    /// Hessenberg matrix `H` satisfies `A Q_k = Q_{k+1} H`, with `Q_k` the first `k` columns of `Q`.
+    ///
+    /// Returns `None` if the matrix is not square, `q0` has the wrong length or is zero, or the
+    /// process breaks down because a new basis vector is (near) zero.
+    pub fn arnoldi(&self, q0: &[f64], steps: usize) -> Option<(Self, Self)> {
+        let size = self.dimensions.rows();
+        if !self.is_square() || q0.len() != size {
+            return None;
+        }
+
+        let norm = dot_product(q0, q0).sqrt();
+        if norm < EPSILON {
+            return None;
+        }
+
+        let mut basis = vec![q0.iter().map(|item| item / norm).collect_vec()];
+        let mut hessenberg = Self::zero(Dimensions::from((steps + 1, steps)));
+
+        for j in 0..steps {
+            let mut w = self.mul_vector(&basis[j]);
+            for (i, vector) in basis.iter().enumerate() {
+                let projection = dot_product(&w, vector);
+                hessenberg.buffer[i * steps + j] = projection;
+                for (item, vector_item) in w.iter_mut().zip(vector.iter()) {
+                    *item -= projection * vector_item;
+                }
+            }
+
+            let norm = dot_product(&w, &w).sqrt();
+            if norm < EPSILON {
+                return None;
+            }
+            hessenberg.buffer[(j + 1) * steps + j] = norm;
+            basis.push(w.iter().map(|item| item / norm).collect());
+        }
+
+        let q = Self::from_buffer(basis.concat(), Dimensions::from((steps + 1, size)))
+            .ok()?
+            .transposed();
+
+        Some((q, hessenberg))
+    }
+
+    /// Runs `steps` iterations of the Lanczos process on a symmetric matrix starting from `q0`.
+    ///
+    /// Returns `(Q, alpha, beta)` where the `k` columns of the `n × k` matrix `Q` form an orthonormal
+    /// basis of the Krylov subspace spanned by `q0, A q0, …, A^(k-1) q0`, and `alpha` (length `k`)
+    /// and `beta` (length `k - 1`) are the diagonal and off-diagonal of the symmetric tridiagonal
+    /// matrix `T = Qᵀ A Q`. The eigenvalues of `T` approximate the extreme eigenvalues of `A`.
+    /// Each new vector is reorthogonalized against the whole basis to keep `Q` orthonormal.
+    ///
+    /// Returns `None` if the matrix is not square, `steps` is zero, `q0` has the wrong length or is
+    /// zero, or the process breaks down because a new basis vector is (near) zero.
+    pub fn lanczos(&self, q0: &[f64], steps: usize) -> Option<(Self, Vec<f64>, Vec<f64>)> {
+        let (basis, alpha, beta) = self.lanczos_recurrence(q0, steps, false)?;
+        if alpha.len() < steps {
+            return None;
+        }
+
+        Some((basis.transposed(), alpha, beta))
+    }
+
+    /// Estimates the spectral radius, the largest eigenvalue magnitude, with power iteration.
+    ///
+    /// Starting from the all-ones vector, the vector is repeatedly multiplied by the matrix and
+    /// normalized, and the growth of its norm in one step is taken as the estimate. Iteration stops
+    /// once two consecutive estimates differ by at most `eps`.
+    ///
+    /// Returns `None` if the matrix is not square, the iterate vanishes, or the estimate doesn't
+    /// converge within `iterations` steps.
+    pub fn spectral_radius(&self, iterations: usize, eps: f64) -> Option<f64> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let size = self.dimensions.rows();
+        let mut vector = vec![1.0 / (size as f64).sqrt(); size];
+        let mut previous: Option<f64> = None;
+
+        for _ in 0..iterations {
+            let w = self.mul_vector(&vector);
+            let norm = dot_product(&w, &w).sqrt();
+            if norm < EPSILON {
+                return None;
+            }
+
+            if previous.is_some_and(|previous| (norm - previous).abs() <= eps) {
+                return Some(norm);
+            }
+            previous = Some(norm);
+            vector = w.iter().map(|item| item / norm).collect();
+        }
+
+        None
+    }
+
+    /// Estimates the smallest and largest eigenvalues of a symmetric matrix from a short Lanczos run.
+    ///
+    /// Runs [`Matrix::lanczos`] for `steps` iterations (at most the size of the matrix) from the
+    /// vector `(1, 2, …, n)` and returns the extreme eigenvalues of the resulting tridiagonal
+    /// matrix as `(smallest, largest)`. These always lie within the true spectrum and converge to
+    /// its extremes quickly; with `steps` equal to the size they are exact up to rounding.
+    ///
+    /// The Krylov subspace can turn out to be invariant before `steps` iterations, for instance
+    /// when the matrix has repeated eigenvalues or the start vector lies in the span of some of
+    /// its eigenvectors. The eigenvalues found so far then need not include the extremes, so the
+    /// process restarts from a vector orthogonal to the basis, until `steps` iterations are done.
+    ///
+    /// Returns `None` if the matrix is not square or `steps` is zero.
+    pub fn extreme_eigenvalues(&self, steps: usize) -> Option<(f64, f64)> {
+        let size = self.dimensions.rows();
+        let q0 = (1..=size).map(|i| i as f64).collect_vec();
+        let (_, alpha, beta) = self.lanczos_recurrence(&q0, steps.min(size), true)?;
+
+        Some((
+            tridiagonal_eigenvalue(&alpha, &beta, 0),
+            tridiagonal_eigenvalue(&alpha, &beta, alpha.len() - 1),
+        ))
+    }
+
+    /// Solves `A x = b` for a general square `A` with the restarted GMRES method.
+    ///
+    /// Each cycle builds an orthonormal Krylov basis of up to `restart` vectors with the Arnoldi
+    /// iteration and minimizes the residual over it, solving the least-squares problem on the
+    /// upper Hessenberg matrix with Givens rotations. `b` must be a column matrix. Iteration stops
+    /// once the Euclidean norm of the residual is at most `epsilon`.
+    ///
+    /// Returns `None` if the dimensions don't match, `restart` is zero, the iteration breaks down,
+    /// or it doesn't converge within `iterations` Arnoldi steps in total.
+    pub fn gmres(
+        &self,
+        b: &Matrix,
+        restart: usize,
+        iterations: usize,
+        epsilon: f64,
+    ) -> Option<Self> {
+        let size = self.dimensions.rows();
+        if !self.is_square() || b.dimensions != Dimensions::from((size, 1)) || restart == 0 {
+            return None;
+        }
+
+        let mut x = vec![0.0; size];
+        let mut steps = 0;
+
+        loop {
+            let residual = b
+                .buffer
+                .iter()
+                .zip(self.mul_vector(&x))
+                .map(|(b_item, ax_item)| b_item - ax_item)
+                .collect_vec();
+            let beta = dot_product(&residual, &residual).sqrt();
+            if beta <= epsilon {
+                return Self::from_buffer(x, b.dimensions).ok();
+            }
+            if steps >= iterations {
+                return None;
+            }
+
+            let mut basis = vec![residual.iter().map(|item| item / beta).collect_vec()];
+            let mut hessenberg = vec![vec![0.0; restart]; restart + 1];
+            let (mut cosines, mut sines) = (vec![0.0; restart], vec![0.0; restart]);
+            let mut rhs = vec![0.0; restart + 1];
+            rhs[0] = beta;
+
+            let mut dimension = 0;
+            let mut lucky_breakdown = false;
+            while dimension < restart && steps < iterations {
+                let j = dimension;
+                let mut w = self.mul_vector(&basis[j]);
+                for (i, vector) in basis.iter().enumerate() {
+                    let projection = dot_product(&w, vector);
+                    hessenberg[i][j] = projection;
+                    for (item, vector_item) in w.iter_mut().zip(vector.iter()) {
+                        *item -= projection * vector_item;
+                    }
+                }
+                let norm = dot_product(&w, &w).sqrt();
+                hessenberg[j + 1][j] = norm;
+
+                for i in 0..j {
+                    let (top, bottom) = (hessenberg[i][j], hessenberg[i + 1][j]);
+                    hessenberg[i][j] = cosines[i] * top + sines[i] * bottom;
+                    hessenberg[i + 1][j] = -sines[i] * top + cosines[i] * bottom;
+                }
+
+                let radius = hessenberg[j][j].hypot(hessenberg[j + 1][j]);
+                if radius < EPSILON {
+                    return None;
+                }
+                cosines[j] = hessenberg[j][j] / radius;
+                sines[j] = hessenberg[j + 1][j] / radius;
+                hessenberg[j][j] = radius;
+                hessenberg[j + 1][j] = 0.0;
+                rhs[j + 1] = -sines[j] * rhs[j];
+                rhs[j] *= cosines[j];
+
+                dimension += 1;
+                steps += 1;
+
+                if rhs[j + 1].abs() <= epsilon {
+                    break;
+                }
+                if norm < EPSILON {
+                    lucky_breakdown = true;
+                    break;
+                }
+                basis.push(w.iter().map(|item| item / norm).collect());
+            }
+
+            let mut y = vec![0.0; dimension];
+            for i in (0..dimension).rev() {
+                let sum = (i + 1..dimension)
+                    .map(|k| hessenberg[i][k] * y[k])
+                    .sum::<f64>();
+                y[i] = (rhs[i] - sum) / hessenberg[i][i];
+            }
+            for (coefficient, vector) in y.iter().zip(basis.iter()) {
+                for (item, vector_item) in x.iter_mut().zip(vector.iter()) {
+                    *item += coefficient * vector_item;
+                }
+            }
+
+            if lucky_breakdown && rhs[dimension].abs() > epsilon {
+                return None;
+            }
+        }
+    }
+
+    /// Solves `A x = b` for a banded matrix `A` with `lower` subdiagonals and `upper` superdiagonals.
+    ///
+    /// Elimination is performed without pivoting and restricted to the band, so the cost is
+    /// `O(n · lower · upper)` per right-hand side instead of `O(n³)`. `b` may have several columns,
+    /// each of which is solved for.
+    ///
+    /// Returns `None` if the matrix is not square, `b` doesn't have as many rows as the matrix,
+    /// or a zero pivot is encountered.
+    pub fn banded_solve(&self, b: &Matrix, lower: usize, upper: usize) -> Option<Self> {
+        let size = self.dimensions.rows();
+        if !self.is_square() || b.dimensions.rows() != size {
+            return None;
+        }
+
+        let right_hand_sides = b.dimensions.columns();
+        let mut work = self.buffer.clone();
+        let mut solution = b.buffer.clone();
+
+        for k in 0..size {
+            let pivot = work[k * size + k];
+            if pivot.abs() < EPSILON {
+                return None;
+            }
+
+            let last_column = (k + upper).min(size - 1);
+            for i in k + 1..=(k + lower).min(size - 1) {
+                let factor = work[i * size + k] / pivot;
+                if factor == 0.0 {
+                    continue;
+                }
+
+                for j in k..=last_column {
+                    work[i * size + j] -= factor * work[k * size + j];
+                }
+                for c in 0..right_hand_sides {
+                    solution[i * right_hand_sides + c] -=
+                        factor * solution[k * right_hand_sides + c];
+                }
+            }
+        }
+
+        for i in (0..size).rev() {
+            let last_column = (i + upper).min(size - 1);
+            for c in 0..right_hand_sides {
+                let sum = (i + 1..=last_column)
+                    .map(|j| work[i * size + j] * solution[j * right_hand_sides + c])
+                    .sum::<f64>();
+                solution[i * right_hand_sides + c] =
+                    (solution[i * right_hand_sides + c] - sum) / work[i * size + i];
+            }
+        }
+
+        Self::from_buffer(solution, b.dimensions).ok()
+    }
+
+    /// Solves a tridiagonal system directly from its three diagonals using the Thomas algorithm.
+    ///
+    /// # Arguments
+    /// * `sub` - The `n - 1` entries below the main diagonal.
+    /// * `diag` - The `n` entries of the main diagonal.
+    /// * `sup` - The `n - 1` entries above the main diagonal.
+    /// * `rhs` - The `n` entries of the right-hand side.
+    ///
+    /// Returns `None` if the lengths are inconsistent or a zero pivot is encountered.
+    pub fn thomas_solve(sub: &[f64], diag: &[f64], sup: &[f64], rhs: &[f64]) -> Option<Vec<f64>> {
+        let size = diag.len();
+        let off_diagonal = size.saturating_sub(1);
+        if sub.len() != off_diagonal || sup.len() != off_diagonal || rhs.len() != size {
+            return None;
+        }
+
+        let mut modified_sup = vec![0.0; off_diagonal];
+        let mut solution = vec![0.0; size];
+
+        for i in 0..size {
+            let (previous_sup, previous_rhs) = match i {
+                0 => (0.0, 0.0),
+                _ => (
+                    sub[i - 1] * modified_sup[i - 1],
+                    sub[i - 1] * solution[i - 1],
+                ),
+            };
+
+            let pivot = diag[i] - previous_sup;
+            if pivot.abs() < EPSILON {
+                return None;
+            }
+
+            if i < off_diagonal {
+                modified_sup[i] = sup[i] / pivot;
+            }
+            solution[i] = (rhs[i] - previous_rhs) / pivot;
+        }
+
+        for i in (0..off_diagonal).rev() {
+            solution[i] -= modified_sup[i] * solution[i + 1];
+        }
+
+        Some(solution)
+    }
+
+    /// Returns the inverse of the matrix, computed by Gauss-Jordan elimination with partial pivoting.
+    ///
+    /// Returns `None` if the matrix is not square or is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let size = self.dimensions.rows();
+        let mut left = self.clone();
+        let mut right = Self::identity(size);
+
+        for pivot in 0..size {
+            let pivot_row = (pivot..size)
+                .max_by(|&a, &b| {
+                    left.buffer[a * size + pivot]
+                        .abs()
+                        .total_cmp(&left.buffer[b * size + pivot].abs())
+                })
+                .unwrap();
+
+            let pivot_value = left.buffer[pivot_row * size + pivot];
+            if pivot_value.abs() < EPSILON {
+                return None;
+            }
+
+            left.swap_rows(pivot, pivot_row);
+            right.swap_rows(pivot, pivot_row);
+
+            for j in 0..size {
+                left.buffer[pivot * size + j] /= pivot_value;
+                right.buffer[pivot * size + j] /= pivot_value;
+            }
+
+            for i in (0..size).filter(|&i| i != pivot) {
+                let factor = left.buffer[i * size + pivot];
+                if factor == 0.0 {
+                    continue;
+                }
+
+                for j in 0..size {
+                    left.buffer[i * size + j] -= factor * left.buffer[pivot * size + j];
+                    right.buffer[i * size + j] -= factor * right.buffer[pivot * size + j];
+                }
+            }
+        }
+
+        Some(right)
+    }
+
+    /// Returns the singular values of the matrix in descending order.
+    ///
+    /// Computed with the one-sided Jacobi method, which orthogonalizes the columns by plane
+    /// rotations; the singular values are the norms of the resulting columns. An `m × n` matrix
+    /// has `min(m, n)` singular values.
+    pub fn singular_values(&self) -> Vec<f64> {
+        if self.dimensions.rows() < self.dimensions.columns() {
+            return self.transposed().singular_values();
+        }
+
+        let (columns, _) = self.jacobi_rotations();
+        let mut values = columns
+            .iter()
+            .map(|column| dot_product(column, column).sqrt())
+            .collect_vec();
+        values.sort_by(|first, second| second.total_cmp(first));
+
+        values
+    }
+
+    /// Returns the condition number `σ_max / σ_min` of the matrix, the ratio of its largest to
+    /// smallest singular value.
+    ///
+    /// Values close to 1 indicate a well-conditioned matrix; large values (say above `1e12`) mean
+    /// solutions of `A x = b` are sensitive to perturbations and may be unreliable. Returns
+    /// `f64::INFINITY` if the smallest singular value is negligible next to the largest one, so
+    /// the matrix is effectively singular, and `None` if the matrix is not square or is empty.
+    pub fn condition_number(&self) -> Option<f64> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let values = self.singular_values();
+        let (&largest, &smallest) = (values.first()?, values.last()?);
+        if smallest <= EPSILON * largest {
+            return Some(f64::INFINITY);
+        }
+
+        Some(largest / smallest)
+    }
+
+    /// Returns `(A + U C V)⁻¹` from a known `A⁻¹` using the Sherman-Morrison-Woodbury identity
+    /// `A⁻¹ - A⁻¹ U (C⁻¹ + V A⁻¹ U)⁻¹ V A⁻¹`.
+    ///
+    /// # Arguments
+    /// * `a_inv` - The `n × n` inverse of `A`.
+    /// * `u` - An `n × k` matrix.
+    /// * `c` - A `k × k` matrix.
+    /// * `v` - A `k × n` matrix.
+    ///
+    /// Returns `None` if the dimensions are inconsistent or if `C` or the capacitance matrix
+    /// `C⁻¹ + V A⁻¹ U` is singular.
+    pub fn woodbury_inverse(a_inv: &Self, u: &Self, c: &Self, v: &Self) -> Option<Self> {
+        let (n, k) = (a_inv.dimensions.rows(), c.dimensions.rows());
+        if !a_inv.is_square()
+            || !c.is_square()
+            || u.dimensions != Dimensions::from((n, k))
+            || v.dimensions != Dimensions::from((k, n))
+        {
+            return None;
+        }
+
+        // Every product below is defined once the dimensions above have been validated.
+        let a_inv_u = (a_inv.clone() * u.clone()).unwrap();
+        let v_a_inv = (v.clone() * a_inv.clone()).unwrap();
+        let capacitance = c.inverse()? + (v.clone() * a_inv_u.clone()).unwrap();
+        let correction = (a_inv_u * capacitance.inverse()?).unwrap();
+
+        Some(a_inv.clone() - (correction * v_a_inv).unwrap())
+    }
+
+    /// Returns `(A + u vᵀ)⁻¹` from a known `A⁻¹` using the Sherman-Morrison formula
+    /// `A⁻¹ - (A⁻¹ u)(vᵀ A⁻¹) / (1 + vᵀ A⁻¹ u)` in `O(n²)` operations.
+    ///
+    /// Returns `None` if `a_inv` is not square, `u` or `v` doesn't match its size,
+    /// or the denominator `1 + vᵀ A⁻¹ u` is zero.
+    pub fn sherman_morrison(a_inv: &Self, u: &[f64], v: &[f64]) -> Option<Self> {
+        let size = a_inv.dimensions.rows();
+        if !a_inv.is_square() || u.len() != size || v.len() != size {
+            return None;
+        }
+
+        let a_inv_u = a_inv
+            .rows()
+            .iter()
+            .map(|row| dot_product(row, u))
+            .collect_vec();
+        let v_a_inv = a_inv
+            .columns()
+            .iter()
+            .map(|column| dot_product(v, column))
+            .collect_vec();
+
+        let denominator = 1.0 + dot_product(v, &a_inv_u);
+        if denominator.abs() < EPSILON {
+            return None;
+        }
+
+        let buffer = a_inv
+            .buffer
+            .iter()
             .enumerate()
-            .all(|(index, item)| index % divisor == 0 || item == &0.0)
+            .map(|(index, item)| item - a_inv_u[index / size] * v_a_inv[index % size] / denominator)
+            .collect();
+
+        Self::from_buffer(buffer, a_inv.dimensions).ok()
     }
 
-    /// Returns `true` if the matrix is an identity matrix.
-    pub fn is_identity(&self) -> bool {
-        self == &Self::identity(self.dimensions.rows())
+    /// Returns the Schur complement `D - C A⁻¹ B` of the leading `k × k` block `A`.
+    ///
+    /// The matrix is partitioned as `[[A, B], [C, D]]`, so the result is `(n - k) × (n - k)`.
+    ///
+    /// Returns `None` if the matrix is not square, `k` does not split it into two non-empty
+    /// blocks, or `A` is singular.
+    pub fn schur_complement(&self, k: usize) -> Option<Self> {
+        if !self.is_square() || k == 0 || k >= self.dimensions.rows() {
+            return None;
+        }
+
+        let size = self.dimensions.rows();
+        let a = self.submatrix(0..k, 0..k)?;
+        let b = self.submatrix(0..k, k..size)?;
+        let c = self.submatrix(k..size, 0..k)?;
+
+        // The block dimensions are consistent by construction, so the product is defined.
+        let a_inverse_b = (a.inverse()? * b).unwrap();
+        Some(self.schur_complement_from(k, &c, &a_inverse_b))
+    }
+
+    /// Returns the inverse of the matrix, computed blockwise through the Schur complement.
+    ///
+    /// The matrix is partitioned as `[[A, B], [C, D]]` where `A` is the leading
+    /// `block_size × block_size` block. With `S = D - C A⁻¹ B`, the inverse is
+    /// `[[A⁻¹ + A⁻¹ B S⁻¹ C A⁻¹, -A⁻¹ B S⁻¹], [-S⁻¹ C A⁻¹, S⁻¹]]`.
+    ///
+    /// Returns `None` if the matrix is not square, `block_size` does not split it into two
+    /// non-empty blocks, or either `A` or `S` is singular.
+    pub fn block_inverse(&self, block_size: usize) -> Option<Self> {
+        if !self.is_square() || block_size == 0 || block_size >= self.dimensions.rows() {
+            return None;
+        }
+
+        let size = self.dimensions.rows();
+        let a = self.submatrix(0..block_size, 0..block_size)?;
+        let b = self.submatrix(0..block_size, block_size..size)?;
+        let c = self.submatrix(block_size..size, 0..block_size)?;
+
+        // The block dimensions are consistent by construction, so every product below is defined.
+        let a_inverse = a.inverse()?;
+        let a_inverse_b = (a_inverse.clone() * b).unwrap();
+        let s_inverse = self
+            .schur_complement_from(block_size, &c, &a_inverse_b)
+            .inverse()?;
+        let c_a_inverse = (c * a_inverse.clone()).unwrap();
+
+        let top_right = -(a_inverse_b * s_inverse.clone()).unwrap();
+        let bottom_left = -(s_inverse.clone() * c_a_inverse.clone()).unwrap();
+        let top_left = a_inverse - (top_right.clone() * c_a_inverse).unwrap();
+
+        let mut result = Self::zero(self.dimensions);
+        result.set_block(0, 0, &top_left);
+        result.set_block(0, block_size, &top_right);
+        result.set_block(block_size, 0, &bottom_left);
+        result.set_block(block_size, block_size, &s_inverse);
+
+        Some(result)
     }
 }
 
-impl TryFrom<Vec<Vec<f64>>> for Matrix {
+impl<T: Scalar> TryFrom<Vec<Vec<T>>> for Matrix<T> {
     type Error = ErrorKind;
-    fn try_from(collection: Vec<Vec<f64>>) -> Result<Self, Self::Error> {
+    fn try_from(collection: Vec<Vec<T>>) -> Result<Self, Self::Error> {
         if !collection.iter().map(|row| row.len()).all_equal() {
             return Err(ErrorKind::DimensionsIncorrct(
                 "Row sizes should be equal.".to_string(),
             ));
         }
 
-        let dimensions = Dimensions::from((collection.len(), collection[0].len()));
+        let dimensions =
+            Dimensions::from((collection.len(), collection.first().map_or(0, Vec::len)));
 
         Ok(Self {
             buffer: collection.concat(),
@@ -464,8 +2789,30 @@ impl TryFrom<Vec<Vec<f64>>> for Matrix {
     }
 }
 
-impl Matrix {
-    fn from_buffer(buffer: Vec<f64>, dimensions: Dimensions) -> Result<Self, ErrorKind> {
+impl<T: Scalar> IntoIterator for Matrix<T> {
+    type Item = Vec<T>;
+    type IntoIter = std::vec::IntoIter<Vec<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows().into_iter()
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a Matrix<T> {
+    type Item = &'a [T];
+    type IntoIter = Either<std::slice::ChunksExact<'a, T>, iter::RepeatN<&'a [T]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // `chunks_exact` can't produce empty chunks, so matrices without columns are handled apart.
+        match self.dimensions.columns() {
+            0 => Either::Right(iter::repeat_n(&self.buffer[..0], self.dimensions.rows())),
+            columns => Either::Left(self.buffer.chunks_exact(columns)),
+        }
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    fn from_buffer(buffer: Vec<T>, dimensions: Dimensions) -> Result<Self, ErrorKind> {
         if buffer.len() != dimensions.count() {
             return Err(ErrorKind::DimensionsIncorrct(
                 "Dimensions don't match the input size.".to_string(),
@@ -474,82 +2821,646 @@ impl Matrix {
 
         Ok(Self { buffer, dimensions })
     }
-}
 
-impl ops::Mul<f64> for Matrix {
-    type Output = Matrix;
+    /// Checks that the number of columns of `self` matches the number of rows of `other`,
+    /// without which the product of the two matrices is undefined.
+    fn check_product(&self, other: &Self) -> Result<(), ErrorKind> {
+        if self.dimensions.columns() != other.dimensions.rows() {
+            return Err(ErrorKind::DimensionsIncorrct(format!(
+                "Cannot multiply a {}x{} matrix by a {}x{} matrix: inner dimensions {} and {} differ.",
+                self.dimensions.rows(),
+                self.dimensions.columns(),
+                other.dimensions.rows(),
+                other.dimensions.columns(),
+                self.dimensions.columns(),
+                other.dimensions.rows(),
+            )));
+        }
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        Self {
-            buffer: self.buffer.iter().map(|item| item * rhs).collect_vec(),
+        Ok(())
+    }
+
+    /// Combines corresponding elements of two equally sized matrices with `f`.
+    fn zip_with(&self, other: &Self, f: impl Fn(T, T) -> T) -> Result<Self, ErrorKind> {
+        if !self.is_same_size(other) {
+            return Err(ErrorKind::DimensionsIncorrct(
+                "Matrices should be of the same dimensions.".to_string(),
+            ));
+        }
+
+        let buffer = self
+            .buffer
+            .iter()
+            .zip(other.buffer.iter())
+            .map(|(&self_item, &other_item)| f(self_item, other_item))
+            .collect();
+
+        Ok(Self {
+            buffer,
             dimensions: self.dimensions,
+        })
+    }
+
+    fn zip_with_into(
+        &self,
+        other: &Self,
+        out: &mut Self,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<(), ErrorKind> {
+        if !self.is_same_size(other) || !self.is_same_size(out) {
+            return Err(ErrorKind::DimensionsIncorrct(
+                "Matrices should be of the same dimensions.".to_string(),
+            ));
         }
+
+        for ((item, &self_item), &other_item) in out
+            .buffer
+            .iter_mut()
+            .zip(self.buffer.iter())
+            .zip(other.buffer.iter())
+        {
+            *item = f(self_item, other_item);
+        }
+
+        Ok(())
     }
-}
 
-impl ops::Mul<Matrix> for f64 {
-    type Output = Matrix;
+    /// Transposes a square matrix in place by swapping each element above the main diagonal
+    /// with its mirror below it.
+    fn transpose_square(&mut self) {
+        let size = self.dimensions.rows();
+        for i in 0..size {
+            for j in i + 1..size {
+                self.buffer.swap(i * size + j, j * size + i);
+            }
+        }
+    }
 
-    fn mul(self, rhs: Matrix) -> Self::Output {
-        rhs * self
+    /// Builds a matrix of the given dimensions from its columns.
+    fn from_columns(columns: Vec<Vec<T>>, dimensions: Dimensions) -> Option<Self> {
+        Self::from_buffer(columns.concat(), dimensions.transposed())
+            .ok()
+            .map(|transposed| transposed.transposed())
+    }
+
+    /// Swaps rows `a` and `b` in place.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let columns = self.dimensions.columns();
+        for j in 0..columns {
+            self.buffer.swap(a * columns + j, b * columns + j);
+        }
+    }
+
+    /// Copies `block` into the matrix with its top-left corner at row `i` and column `j`.
+    ///
+    /// The caller must ensure the block fits inside the matrix.
+    fn set_block(&mut self, i: usize, j: usize, block: &Self) {
+        let (columns, block_columns) = (self.dimensions.columns(), block.dimensions.columns());
+        for row_index in 0..block.dimensions.rows() {
+            let start = (i + row_index) * columns + j;
+            let block_start = row_index * block_columns;
+            self.buffer[start..start + block_columns]
+                .copy_from_slice(&block.buffer[block_start..block_start + block_columns]);
+        }
     }
 }
 
-impl ops::Mul for Matrix {
-    type Output = Option<Self>;
+impl Matrix {
+    /// Maps every element to `1.0` if it satisfies `predicate` and to `0.0` otherwise.
+    fn mask(&self, predicate: impl Fn(f64) -> bool) -> Self {
+        self.map(|item| if predicate(item) { 1.0 } else { 0.0 })
+    }
 
-    fn mul(self, other: Self) -> Self::Output {
-        // If the number of columns of the first matrix doesn't match the number of rows of the second matrix,
-        // product of the two matrices is undifined.
-        if self.dimensions.columns() != other.dimensions.rows() {
+    /// Runs up to `steps` iterations of the Lanczos process, returning the basis vectors as the rows
+    /// of a matrix along with the diagonal `alpha` and off-diagonal `beta` of the tridiagonal matrix.
+    ///
+    /// Once the Krylov subspace is invariant and the next basis vector would be (near) zero, the
+    /// process either stops early, with fewer than `steps` basis vectors, or with `restart` set
+    /// continues from the unit vector with the largest component orthogonal to the basis, recording
+    /// a zero in `beta`. `steps` must not exceed the size of the matrix for the restart to exist.
+    /// Returns `None` if the matrix is not square, `steps` is zero, or `q0` has the wrong length
+    /// or is zero.
+    fn lanczos_recurrence(
+        &self,
+        q0: &[f64],
+        steps: usize,
+        restart: bool,
+    ) -> Option<(Self, Vec<f64>, Vec<f64>)> {
+        let size = self.dimensions.rows();
+        if !self.is_square() || steps == 0 || q0.len() != size {
+            return None;
+        }
+
+        let norm = dot_product(q0, q0).sqrt();
+        if norm < EPSILON {
             return None;
         }
 
-        let (self_rows, mutual_dimension, other_columns) = (
+        let orthogonalize = |w: &mut Vec<f64>, basis: &[Vec<f64>]| {
+            for vector in basis {
+                let projection = dot_product(w, vector);
+                for (item, vector_item) in w.iter_mut().zip(vector.iter()) {
+                    *item -= projection * vector_item;
+                }
+            }
+        };
+
+        let mut basis = vec![q0.iter().map(|item| item / norm).collect_vec()];
+        let mut alpha = Vec::with_capacity(steps);
+        let mut beta: Vec<f64> = Vec::with_capacity(steps - 1);
+
+        for j in 0..steps {
+            let mut w = self.mul_vector(&basis[j]);
+            alpha.push(dot_product(&w, &basis[j]));
+            orthogonalize(&mut w, &basis);
+
+            if j + 1 == steps {
+                break;
+            }
+
+            let mut norm = dot_product(&w, &w).sqrt();
+            if norm < EPSILON {
+                if !restart {
+                    break;
+                }
+
+                // The unit vectors span the whole space, so one of them has a component of norm at
+                // least `sqrt((size - j - 1) / size)` outside the current basis.
+                (norm, w) = (0..size)
+                    .map(|i| {
+                        let mut unit = vec![0.0; size];
+                        unit[i] = 1.0;
+                        orthogonalize(&mut unit, &basis);
+                        (dot_product(&unit, &unit).sqrt(), unit)
+                    })
+                    .max_by(|(first, _), (second, _)| first.total_cmp(second))?;
+                beta.push(0.0);
+            } else {
+                beta.push(norm);
+            }
+            basis.push(w.iter().map(|item| item / norm).collect());
+        }
+
+        let basis =
+            Self::from_buffer(basis.concat(), Dimensions::from((alpha.len(), size))).ok()?;
+
+        Some((basis, alpha, beta))
+    }
+
+    /// Returns the Schur complement `D - C A⁻¹ B` of the leading `k × k` block, given the blocks
+    /// `C` and `A⁻¹ B` already computed by the caller.
+    fn schur_complement_from(&self, k: usize, c: &Self, a_inverse_b: &Self) -> Self {
+        let size = self.dimensions.rows();
+        // The block dimensions are consistent by construction, so the block and product exist.
+        let d = self.submatrix(k..size, k..size).unwrap();
+        d - (c.clone() * a_inverse_b.clone()).unwrap()
+    }
+
+    /// Returns a `rows × columns` copy of the matrix, extended with zeros to the right and below.
+    fn padded(&self, rows: usize, columns: usize) -> Self {
+        let mut result = Self::zero(Dimensions::from((rows, columns)));
+        result.set_block(0, 0, self);
+
+        result
+    }
+
+    /// Multiplies two matrices with compatible dimensions using Strassen's recursion.
+    fn strassen_product(&self, other: &Self) -> Self {
+        let (rows, inner, columns) = (
             self.dimensions.rows(),
             self.dimensions.columns(),
             other.dimensions.columns(),
         );
+        // The dimensions were checked by the caller.
+        if rows.min(inner).min(columns) < STRASSEN_THRESHOLD {
+            return self.try_mul(other).unwrap();
+        }
 
-        let mut result_collection: Vec<f64> = Vec::new();
+        let even = |size: usize| size + size % 2;
+        if (rows, inner, columns) != (even(rows), even(inner), even(columns)) {
+            return self
+                .padded(even(rows), even(inner))
+                .strassen_product(&other.padded(even(inner), even(columns)))
+                .submatrix(0..rows, 0..columns)
+                .unwrap();
+        }
 
-        let mut result_collection = Vec::with_capacity(self_rows * other_columns);
+        let quadrants = |matrix: &Self| {
+            let (rows, columns) = (matrix.dimensions.rows(), matrix.dimensions.columns());
+            let (half_rows, half_columns) = (rows / 2, columns / 2);
+            [
+                (0..half_rows, 0..half_columns),
+                (0..half_rows, half_columns..columns),
+                (half_rows..rows, 0..half_columns),
+                (half_rows..rows, half_columns..columns),
+            ]
+            .map(|(rows, columns)| matrix.submatrix(rows, columns).unwrap())
+        };
+        let [a11, a12, a21, a22] = quadrants(self);
+        let [b11, b12, b21, b22] = quadrants(other);
+
+        let m1 = (a11.clone() + a22.clone()).strassen_product(&(b11.clone() + b22.clone()));
+        let m2 = (a21.clone() + a22.clone()).strassen_product(&b11);
+        let m3 = a11.strassen_product(&(b12.clone() - b22.clone()));
+        let m4 = a22.strassen_product(&(b21.clone() - b11.clone()));
+        let m5 = (a11.clone() + a12.clone()).strassen_product(&b22);
+        let m6 = (a21 - a11).strassen_product(&(b11 + b12));
+        let m7 = (a12 - a22).strassen_product(&(b21 + b22));
+
+        let c11 = m1.clone() + m4.clone() - m5.clone() + m7;
+        let c12 = m3.clone() + m5;
+        let c21 = m2.clone() + m4;
+        let c22 = m1 - m2 + m3 + m6;
+
+        Self::block(vec![vec![c11, c12], vec![c21, c22]]).unwrap()
+    }
 
-        for self_i in 0..self_rows {
-            for other_j in 0..other_columns {
-                result_collection.push(dot_product(
-                    self.row(self_i).unwrap(),
-                    other.column(other_j).unwrap(),
-                ));
+    /// Reduces a copy of the matrix to row echelon form by Gaussian elimination with partial pivoting.
+    ///
+    /// Returns the reduced matrix together with the column index of each pivot, in row order.
+    fn echelon(&self) -> (Self, Vec<usize>) {
+        let (rows, columns) = (self.dimensions.rows(), self.dimensions.columns());
+        let mut work = self.clone();
+        let mut pivot_columns = Vec::new();
+
+        for column in 0..columns {
+            let pivot = pivot_columns.len();
+            if pivot == rows {
+                break;
+            }
+
+            let pivot_row = (pivot..rows)
+                .max_by(|&a, &b| {
+                    work.buffer[a * columns + column]
+                        .abs()
+                        .total_cmp(&work.buffer[b * columns + column].abs())
+                })
+                .unwrap();
+
+            if work.buffer[pivot_row * columns + column].abs() < EPSILON {
+                for i in pivot..rows {
+                    work.buffer[i * columns + column] = 0.0;
+                }
+                continue;
+            }
+
+            work.swap_rows(pivot, pivot_row);
+
+            let pivot_value = work.buffer[pivot * columns + column];
+            for i in pivot + 1..rows {
+                let factor = work.buffer[i * columns + column] / pivot_value;
+                work.buffer[i * columns + column] = 0.0;
+                for j in column + 1..columns {
+                    work.buffer[i * columns + j] -= factor * work.buffer[pivot * columns + j];
+                }
             }
+
+            pivot_columns.push(column);
         }
 
-        Some(Self {
-            buffer: result_collection,
-            dimensions: Dimensions::from((self_rows, other_columns)),
-        })
+        (work, pivot_columns)
+    }
+
+    /// Factors a square matrix as `PA = LU` using Gaussian elimination with partial pivoting.
+    ///
+    /// Returns the packed factors (`L` strictly below the diagonal with an implicit unit diagonal,
+    /// `U` on and above it), the row permutation and its sign. Elimination continues past zero
+    /// pivots, so singular matrices still produce a factorization with a zero on the diagonal of `U`.
+    ///
+    /// Returns `None` if the matrix is not square.
+    fn lu_factors(&self) -> Option<(Vec<f64>, Vec<usize>, f64)> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let size = self.dimensions.rows();
+        let mut work = self.clone();
+        let mut permutation = (0..size).collect_vec();
+        let mut sign = 1.0;
+
+        for k in 0..size {
+            let pivot_row = (k..size)
+                .max_by(|&a, &b| {
+                    work.buffer[a * size + k]
+                        .abs()
+                        .total_cmp(&work.buffer[b * size + k].abs())
+                })
+                .unwrap();
+
+            if pivot_row != k {
+                work.swap_rows(k, pivot_row);
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = work.buffer[k * size + k];
+            if pivot.abs() < EPSILON {
+                continue;
+            }
+
+            for i in k + 1..size {
+                let factor = work.buffer[i * size + k] / pivot;
+                work.buffer[i * size + k] = factor;
+                for j in k + 1..size {
+                    work.buffer[i * size + j] -= factor * work.buffer[k * size + j];
+                }
+            }
+        }
+
+        Some((work.buffer, permutation, sign))
+    }
+
+    /// Applies one-sided Jacobi rotations to the columns until they are mutually orthogonal.
+    ///
+    /// Returns the columns of `A V` and the columns of the orthogonal `n × n` matrix `V`
+    /// accumulating the rotations, so that `A = (A V) Vᵀ`.
+    fn jacobi_rotations(&self) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        const MAX_SWEEPS: usize = 64;
+
+        let size = self.dimensions.columns();
+        let mut columns = self.columns();
+        let mut v = Self::identity(size).columns();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in (0..size).tuple_combinations() {
+                let alpha = dot_product(&columns[p], &columns[p]);
+                let beta = dot_product(&columns[q], &columns[q]);
+                let gamma = dot_product(&columns[p], &columns[q]);
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let cos = 1.0 / (1.0 + t * t).sqrt();
+                let sin = cos * t;
+                for vectors in [&mut columns, &mut v] {
+                    let (head, tail) = vectors.split_at_mut(q);
+                    for (first, second) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        (*first, *second) =
+                            (cos * *first - sin * *second, sin * *first + cos * *second);
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        (columns, v)
+    }
+
+    /// Returns the matrix-vector product `A x`.
+    ///
+    /// The caller must ensure `x` has as many elements as the matrix has columns.
+    fn mul_vector(&self, x: &[f64]) -> Vec<f64> {
+        let columns = self.dimensions.columns();
+        (0..self.dimensions.rows())
+            .map(|i| dot_product(&self.buffer[i * columns..(i + 1) * columns], x))
+            .collect()
+    }
+
+    /// Computes a Cholesky factor `L` from the lower triangle of the matrix.
+    ///
+    /// Below-diagonal elements for which `keep` returns `false` are treated as structural zeros,
+    /// leaving the corresponding entries of `L` zero. Returns `None` if the matrix is not square or
+    /// a pivot, the value whose square root becomes a diagonal entry of `L`, is at most `min_pivot`.
+    fn cholesky_factor(&self, min_pivot: f64, keep: impl Fn(f64) -> bool) -> Option<Self> {
+        if !self.is_square() {
+            return None;
+        }
+
+        let size = self.dimensions.rows();
+        let mut factor = Self::zero(self.dimensions);
+
+        for k in 0..size {
+            let pivot = self.buffer[k * size + k]
+                - (0..k)
+                    .map(|j| factor.buffer[k * size + j].powi(2))
+                    .sum::<f64>();
+            if pivot <= min_pivot {
+                return None;
+            }
+            let pivot = pivot.sqrt();
+            factor.buffer[k * size + k] = pivot;
+
+            for i in k + 1..size {
+                let item = self.buffer[i * size + k];
+                if !keep(item) {
+                    continue;
+                }
+
+                let sum = (0..k)
+                    .map(|j| factor.buffer[i * size + j] * factor.buffer[k * size + j])
+                    .sum::<f64>();
+                factor.buffer[i * size + k] = (item - sum) / pivot;
+            }
+        }
+
+        Some(factor)
+    }
+
+    /// Solves `L Lᵀ z = r` for `z`, reading `L` from the lower triangle of the matrix.
+    ///
+    /// Returns `None` if `L` has a zero on its diagonal.
+    fn cholesky_factor_solve(&self, r: &[f64]) -> Option<Vec<f64>> {
+        let size = self.dimensions.rows();
+        if (0..size).any(|i| self.buffer[i * size + i] == 0.0) {
+            return None;
+        }
+
+        let mut z = vec![0.0; size];
+        for i in 0..size {
+            let sum = (0..i)
+                .map(|j| self.buffer[i * size + j] * z[j])
+                .sum::<f64>();
+            z[i] = (r[i] - sum) / self.buffer[i * size + i];
+        }
+        for i in (0..size).rev() {
+            let sum = (i + 1..size)
+                .map(|j| self.buffer[j * size + i] * z[j])
+                .sum::<f64>();
+            z[i] = (z[i] - sum) / self.buffer[i * size + i];
+        }
+
+        Some(z)
+    }
+}
+
+impl ops::Mul<f64> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            buffer: self.buffer.iter().map(|item| item * rhs).collect_vec(),
+            dimensions: self.dimensions,
+        }
+    }
+}
+
+impl ops::Mul<Matrix> for f64 {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<T: Scalar> ops::Mul for Matrix<T> {
+    type Output = Option<Self>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.try_mul(&other).ok()
     }
 }
 
 /// Computes the dot product of two vectors.
 ///
 /// # Arguments
-/// * `first` - First vector of f64 values.
-/// * `second` - Second vector of f64 values.
+/// * `first` - First slice of values.
+/// * `second` - Second slice of values.
 ///
 /// # Returns
 /// Sum of element-wise products.
 ///
 /// # Panics
 /// Panics if the vectors are of different lengths.
-fn dot_product(first: Vec<f64>, second: Vec<f64>) -> f64 {
+fn dot_product<T: Scalar>(first: &[T], second: &[T]) -> T {
     first
         .iter()
         .zip(second.iter())
-        .map(|(first_item, second_item)| first_item * second_item)
+        .map(|(&first_item, &second_item)| first_item * second_item)
         .sum()
 }
 
+/// Computes the `index`-th smallest eigenvalue of a symmetric tridiagonal matrix by bisection.
+///
+/// # Arguments
+/// * `diagonal` - The main diagonal of the matrix.
+/// * `off_diagonal` - The sub- (and super-) diagonal, one shorter than `diagonal`.
+/// * `index` - Zero-based position of the eigenvalue in ascending order.
+///
+/// # Returns
+/// The eigenvalue, located within the Gershgorin interval using Sturm sequence counts.
+fn tridiagonal_eigenvalue(diagonal: &[f64], off_diagonal: &[f64], index: usize) -> f64 {
+    let radius = |i: usize| {
+        let before = if i > 0 {
+            off_diagonal[i - 1].abs()
+        } else {
+            0.0
+        };
+        before + off_diagonal.get(i).map_or(0.0, |item| item.abs())
+    };
+    let mut lower = (0..diagonal.len())
+        .map(|i| diagonal[i] - radius(i))
+        .fold(f64::INFINITY, f64::min);
+    let mut upper = (0..diagonal.len())
+        .map(|i| diagonal[i] + radius(i))
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    // Number of eigenvalues below `x`: the negative pivots of the LDLᵀ factorization of `T - x I`.
+    let count_below = |x: f64| {
+        let mut pivot = 1.0;
+        let mut count = 0;
+        for (i, item) in diagonal.iter().enumerate() {
+            let coupling = if i > 0 {
+                off_diagonal[i - 1].powi(2)
+            } else {
+                0.0
+            };
+            pivot = item - x - coupling / pivot;
+            if pivot == 0.0 {
+                pivot = f64::MIN_POSITIVE;
+            }
+            if pivot < 0.0 {
+                count += 1;
+            }
+        }
+        count
+    };
+
+    while upper - lower > EPSILON * (1.0 + lower.abs().max(upper.abs())) {
+        let middle = (lower + upper) / 2.0;
+        if middle <= lower || middle >= upper {
+            break;
+        }
+        if count_below(middle) > index {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+
+    (lower + upper) / 2.0
+}
+
+/// Approximates `value` by a fraction from its continued-fraction convergents.
+///
+/// # Arguments
+/// * `value` - The number to approximate.
+/// * `max_denominator` - The largest denominator allowed; `0` is treated as `1`.
+///
+/// # Returns
+/// `(numerator, denominator)` of the last convergent within the bound that fits in `i64`, with
+/// a positive denominator. `NaN` maps to `(0, 0)` and the infinities to `(±1, 0)`. Finite values
+/// beyond the range of `i64` saturate to `(i64::MAX, 1)` or `(i64::MIN, 1)`.
+fn rational_approximation(value: f64, max_denominator: u64) -> (i64, i64) {
+    if value.is_nan() {
+        return (0, 0);
+    }
+    if value.is_infinite() {
+        return (value.signum() as i64, 0);
+    }
+    if value.abs() >= i64::MAX as f64 {
+        return (value as i64, 1);
+    }
+
+    let max_denominator = i64::try_from(max_denominator.max(1)).unwrap_or(i64::MAX);
+    let (mut previous, mut current) = ((0, 1), (1, 0));
+    let mut remainder = value;
+
+    // Terms that don't fit in `i64` would only lead to convergents that overflow.
+    while remainder.abs() < i64::MAX as f64 {
+        let term = remainder.floor() as i64;
+        let convergent =
+            |(previous, current): (i64, i64)| term.checked_mul(current)?.checked_add(previous);
+        // Stop before a convergent overflows `i64`.
+        let (Some(numerator), Some(denominator)) = (
+            convergent((previous.0, current.0)),
+            convergent((previous.1, current.1)),
+        ) else {
+            break;
+        };
+        let next = (numerator, denominator);
+        if next.1 > max_denominator {
+            break;
+        }
+        (previous, current) = (current, next);
+
+        let fraction = remainder - remainder.floor();
+        if fraction == 0.0 || current.0 as f64 / current.1 as f64 == value {
+            break;
+        }
+        remainder = 1.0 / fraction;
+    }
+
+    current
+}
+
+/// Computes the greatest common divisor of two integers with the Euclidean algorithm.
+///
+/// # Returns
+/// The greatest common divisor; `gcd(0, 0)` is `0`.
+fn gcd(mut first: u64, mut second: u64) -> u64 {
+    while second != 0 {
+        (first, second) = (second, first % second);
+    }
+    first
+}
+
 impl ops::Div<f64> for Matrix {
     type Output = Matrix;
 
@@ -561,46 +3472,47 @@ impl ops::Div<f64> for Matrix {
     }
 }
 
-impl ops::Add for Matrix {
+/// # Panics
+/// Panics if the matrices have different dimensions; see [`Matrix::checked_add`].
+impl<T: Scalar> ops::Add for Matrix<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
-        assert!(
-            self.is_same_size(&other),
-            "To add matrices they should be of the the same dimensions."
-        );
-
-        let buffer = self
-            .buffer
-            .iter()
-            .zip(other.buffer.iter())
-            .map(|(self_item, other_item)| self_item + other_item)
-            .collect();
-
-        Self {
-            buffer,
-            dimensions: self.dimensions,
-        }
+        self.checked_add(&other)
+            .expect("To add matrices they should be of the the same dimensions.")
     }
 }
 
-impl ops::Sub for Matrix {
-    type Output = Matrix;
+/// # Panics
+/// Panics if the matrices have different dimensions; see [`Matrix::checked_sub`].
+impl<T: Scalar> ops::Sub for Matrix<T> {
+    type Output = Self;
 
     fn sub(self, other: Self) -> Self::Output {
-        self + (-other)
+        self.checked_sub(&other)
+            .expect("To subtract matrices they should be of the same dimensions.")
     }
 }
 
-impl ops::Neg for Matrix {
-    type Output = Matrix;
+impl<T: Scalar> ops::Neg for Matrix<T> {
+    type Output = Self;
 
     fn neg(self) -> Self::Output {
-        self * -1.0
+        self.map(|item| -item)
+    }
+}
+
+/// The empty 0×0 matrix.
+impl<T: Scalar> Default for Matrix<T> {
+    fn default() -> Self {
+        Self {
+            buffer: Vec::new(),
+            dimensions: Dimensions::Square(0),
+        }
     }
 }
 
-impl PartialEq for Matrix {
+impl<T: Scalar> PartialEq for Matrix<T> {
     fn eq(&self, other: &Self) -> bool {
         self.buffer == other.buffer && self.is_same_size(other)
     }
@@ -0,0 +1,41 @@
+//! The element types a [`Matrix`](crate::Matrix) can hold.
+
+use std::{fmt, iter, ops};
+
+/// A numeric type usable as the element type of a [`Matrix`](crate::Matrix).
+///
+/// Structural operations, such as construction, element access, transposition and the
+/// arithmetic operators, are available for every `Scalar`. Algorithms that rely on division or
+/// square roots, such as decompositions and solvers, are only provided for `f64`.
+///
+/// Implemented for `f32`, `f64` and the signed integer types.
+pub trait Scalar:
+    Copy
+    + Default
+    + PartialEq
+    + fmt::Debug
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Neg<Output = Self>
+    + iter::Sum
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+}
+
+macro_rules! impl_scalar {
+    ($zero:literal, $one:literal; $($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+
+impl_scalar!(0.0, 1.0; f32, f64);
+impl_scalar!(0, 1; i8, i16, i32, i64, i128, isize);
@@ -1,5 +1,8 @@
 #[cfg(test)]
-use crate::{Dimensions, Itertools, Matrix};
+use crate::{
+    Dimensions, ErrorKind, F64Matrix, Itertools, KalmanFilter, Matrix, MatrixView, RlsEstimator,
+    matrix,
+};
 
 #[test]
 fn test_row_works() {
@@ -148,6 +151,30 @@ fn test_properties_set_value_is_x_triangular() {
     assert!(!matrix.is_upper_triangular());
 }
 
+#[test]
+fn test_is_upper_triangular_checks_below_diagonal() {
+    // Entries above the main diagonal don't matter; a single entry below it does.
+    let upper = matrix![[1.0, 2.0, 3.0], [0.0, 0.0, 4.0], [0.0, 0.0, 5.0]];
+    let mut almost_upper = upper.clone();
+    almost_upper.set(2, 1, 1e-3);
+
+    assert!(upper.is_upper_triangular());
+    assert!(!almost_upper.is_upper_triangular());
+    assert!(Matrix::diagonal(vec![1.0, 2.0]).is_upper_triangular());
+    assert!(!matrix![[1.0, 2.0, 3.0], [0.0, 4.0, 5.0]].is_upper_triangular());
+}
+
+#[test]
+fn test_properties_triangular_is_x_triangular() {
+    let upper = matrix![[1.0, 2.0, 3.0], [0.0, 4.0, 5.0], [0.0, 0.0, 6.0]];
+    let lower = upper.transposed();
+
+    assert!(upper.is_upper_triangular());
+    assert!(!upper.is_lower_triangular());
+    assert!(lower.is_lower_triangular());
+    assert!(!lower.is_upper_triangular());
+}
+
 #[test]
 fn test_properties_set_value_is_scalar() {
     let base_collection = vec![
@@ -330,3 +357,2508 @@ fn test_determinant1x1() {
 
     assert_eq!(matrix.determinant_unoptimized(), Some(expected_result));
 }
+
+#[test]
+fn test_rotation_2d_quarter_turn() {
+    let matrix = Matrix::rotation_2d(std::f64::consts::FRAC_PI_2);
+    let point = Matrix::try_from(vec![vec![1.0], vec![0.0]]).unwrap();
+
+    let rotated = (matrix * point).unwrap();
+
+    assert!((rotated.get(0, 0).unwrap() - 0.0).abs() < 1e-12);
+    assert!((rotated.get(1, 0).unwrap() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_rotation_3d_axes_quarter_turn() {
+    let angle = std::f64::consts::FRAC_PI_2;
+    let cases = [
+        (
+            Matrix::rotation_3d_x(angle),
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ),
+        (
+            Matrix::rotation_3d_y(angle),
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+        ),
+        (
+            Matrix::rotation_3d_z(angle),
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ),
+    ];
+
+    for (rotation, input, expected) in cases {
+        let point = Matrix::try_from(input.iter().map(|&item| vec![item]).collect_vec()).unwrap();
+        let rotated = (rotation * point).unwrap();
+
+        for (i, value) in expected.iter().enumerate() {
+            assert!((rotated.get(i, 0).unwrap() - value).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_rotation_3d_axis_is_fixed() {
+    let rotation = Matrix::rotation_3d_z(0.7);
+    let axis = Matrix::try_from(vec![vec![0.0], vec![0.0], vec![1.0]]).unwrap();
+
+    assert_eq!((rotation * axis.clone()).unwrap(), axis);
+}
+
+#[cfg(test)]
+fn assert_matrix_near(actual: &Matrix, expected: &Matrix, epsilon: f64) {
+    assert!(actual.is_same_size(expected), "{actual:?} != {expected:?}");
+    for (actual_row, expected_row) in actual.rows().iter().zip(expected.rows().iter()) {
+        for (actual_item, expected_item) in actual_row.iter().zip(expected_row.iter()) {
+            assert!(
+                (actual_item - expected_item).abs() < epsilon,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_submatrix_controlled_matrix() {
+    let base_collection = vec![
+        vec![1.0, 3.0, 5.0],
+        vec![2.0, 4.0, 6.0],
+        vec![3.0, 7.0, 11.0],
+    ];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    let expected_result = Matrix::try_from(vec![vec![4.0, 6.0], vec![7.0, 11.0]]).unwrap();
+
+    assert_eq!(matrix.submatrix(1..3, 1..3), Some(expected_result));
+    assert_eq!(matrix.submatrix(1..4, 0..1), None);
+}
+
+#[test]
+fn test_inverse_controlled_matrix() {
+    let base_collection = vec![
+        vec![1.0, 3.0, 0.0],
+        vec![2.0, 4.0, 5.0],
+        vec![3.0, 7.0, 6.0],
+    ];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    let inverse = matrix.inverse().unwrap();
+
+    assert_matrix_near(&(matrix * inverse).unwrap(), &Matrix::identity(3), 1e-10);
+}
+
+#[test]
+fn test_inverse_singular_matrix() {
+    let base_collection = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    assert_eq!(matrix.inverse(), None);
+}
+
+#[test]
+fn test_block_inverse_matches_inverse() {
+    let base_collection = vec![
+        vec![4.0, 1.0, 2.0, 0.0],
+        vec![1.0, 5.0, 0.0, 1.0],
+        vec![2.0, 0.0, 6.0, 1.0],
+        vec![0.0, 1.0, 1.0, 3.0],
+    ];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    let expected_result = matrix.inverse().unwrap();
+
+    for block_size in 1..4 {
+        assert_matrix_near(
+            &matrix.block_inverse(block_size).unwrap(),
+            &expected_result,
+            1e-10,
+        );
+    }
+    assert_eq!(matrix.block_inverse(0), None);
+}
+
+#[test]
+fn test_pow_controlled_matrix() {
+    let matrix: Matrix = Matrix::try_from(vec![vec![1.0, 1.0], vec![1.0, 0.0]]).unwrap();
+
+    let expected_result = Matrix::try_from(vec![vec![8.0, 5.0], vec![5.0, 3.0]]).unwrap();
+
+    assert_eq!(matrix.pow(5), Some(expected_result));
+    assert_eq!(matrix.pow(0), Some(Matrix::identity(2)));
+}
+
+#[test]
+fn test_properties_projection_is_idempotent() {
+    let base_collection = vec![vec![0.5, 0.5], vec![0.5, 0.5]];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    assert!(matrix.is_idempotent(1e-12));
+    assert!(!matrix.is_involutory(1e-12));
+    assert!(!matrix.is_nilpotent(4, 1e-12));
+}
+
+#[test]
+fn test_properties_reflection_is_involutory() {
+    let base_collection = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    assert!(matrix.is_involutory(1e-12));
+    assert!(!matrix.is_idempotent(1e-12));
+}
+
+#[test]
+fn test_properties_strictly_upper_triangular_is_nilpotent() {
+    let base_collection = vec![
+        vec![0.0, 2.0, 3.0],
+        vec![0.0, 0.0, 4.0],
+        vec![0.0, 0.0, 0.0],
+    ];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    assert!(matrix.is_nilpotent(3, 1e-12));
+    assert!(!matrix.is_nilpotent(2, 1e-12));
+}
+
+#[test]
+fn test_exchange_reverses_order() {
+    let base_collection = vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0],
+    ];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    let rows_reversed = vec![
+        vec![7.0, 8.0, 9.0],
+        vec![4.0, 5.0, 6.0],
+        vec![1.0, 2.0, 3.0],
+    ];
+    let columns_reversed = vec![
+        vec![3.0, 2.0, 1.0],
+        vec![6.0, 5.0, 4.0],
+        vec![9.0, 8.0, 7.0],
+    ];
+
+    assert_eq!(
+        Matrix::exchange(3) * matrix.clone(),
+        Matrix::try_from(rows_reversed).ok()
+    );
+    assert_eq!(
+        matrix * Matrix::exchange(3),
+        Matrix::try_from(columns_reversed).ok()
+    );
+}
+
+#[test]
+fn test_exchange_is_involutory() {
+    let matrix = Matrix::exchange(4);
+
+    assert_eq!(matrix.secondary_diagonal(), Some(vec![&1.0; 4]));
+    assert!(matrix.is_involutory(0.0));
+}
+
+#[test]
+fn test_woodbury_inverse_matches_direct_inverse() {
+    let a = Matrix::try_from(vec![
+        vec![4.0, 1.0, 0.0],
+        vec![1.0, 3.0, 1.0],
+        vec![0.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    let u = Matrix::try_from(vec![vec![1.0, 0.0], vec![2.0, 1.0], vec![0.0, 1.0]]).unwrap();
+    let c = Matrix::try_from(vec![vec![2.0, 0.0], vec![0.0, 1.0]]).unwrap();
+    let v = Matrix::try_from(vec![vec![1.0, 0.0, 1.0], vec![0.0, 1.0, 1.0]]).unwrap();
+
+    let updated = a.clone() + ((u.clone() * c.clone()).unwrap() * v.clone()).unwrap();
+    let expected_result = updated.inverse().unwrap();
+
+    let result = Matrix::woodbury_inverse(&a.inverse().unwrap(), &u, &c, &v).unwrap();
+
+    assert_matrix_near(&result, &expected_result, 1e-10);
+}
+
+#[test]
+fn test_woodbury_inverse_dimension_mismatch() {
+    let a_inv = Matrix::identity(3);
+    let u = Matrix::zero(Dimensions::Rectangle {
+        rows: 3,
+        columns: 2,
+    });
+    let c = Matrix::identity(2);
+    let v = Matrix::zero(Dimensions::Rectangle {
+        rows: 2,
+        columns: 2,
+    });
+
+    assert_eq!(Matrix::woodbury_inverse(&a_inv, &u, &c, &v), None);
+}
+
+#[test]
+fn test_determinant_matches_unoptimized() {
+    let base_collection = vec![
+        vec![1.0, 3.0, 0.0, 9.0, 7.0],
+        vec![2.0, 4.0, 5.0, 5.0, 2.0],
+        vec![3.0, 7.0, 6.0, 8.0, 1.0],
+        vec![6.0, 5.0, 3.0, 18.0, 8.0],
+        vec![13.0, 23.0, 9.0, 8.0, 6.0],
+    ];
+    let matrix: Matrix = Matrix::try_from(base_collection).unwrap();
+
+    assert!((matrix.determinant().unwrap() - 12435.0).abs() < 1e-8);
+}
+
+#[test]
+fn test_determinant_bareiss_matches_lu_on_integer_matrices() {
+    let collections = vec![
+        vec![vec![1.0, 3.0], vec![2.0, 4.0]],
+        vec![
+            vec![0.0, 3.0, 0.0],
+            vec![2.0, 4.0, 5.0],
+            vec![3.0, 7.0, 6.0],
+        ],
+        vec![
+            vec![1.0, 3.0, 0.0, 9.0],
+            vec![2.0, 4.0, 5.0, 5.0],
+            vec![3.0, 7.0, 6.0, 8.0],
+            vec![6.0, 5.0, 3.0, 18.0],
+        ],
+        vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![1.0, 0.0, 1.0],
+        ],
+    ];
+
+    for collection in collections {
+        let matrix: Matrix = Matrix::try_from(collection).unwrap();
+
+        let bareiss = matrix.determinant_bareiss().unwrap();
+        let lu = matrix.determinant().unwrap();
+
+        assert_eq!(bareiss, bareiss.round());
+        assert_eq!(bareiss, lu.round());
+    }
+}
+
+#[test]
+fn test_determinant_bareiss_non_square() {
+    let matrix = Matrix::zero(Dimensions::Rectangle {
+        rows: 2,
+        columns: 3,
+    });
+
+    assert_eq!(matrix.determinant_bareiss(), None);
+    assert_eq!(matrix.determinant(), None);
+}
+
+#[test]
+fn test_ones_and_full_match_constant() {
+    let dimensions = Dimensions::Rectangle {
+        rows: 2,
+        columns: 3,
+    };
+
+    assert_eq!(Matrix::ones(dimensions), Matrix::constant(dimensions, 1.0));
+    assert_eq!(
+        Matrix::full(dimensions, -2.5),
+        Matrix::constant(dimensions, -2.5)
+    );
+}
+
+#[test]
+fn test_sherman_morrison_matches_direct_inverse() {
+    let a = Matrix::try_from(vec![
+        vec![4.0, 1.0, 0.0],
+        vec![1.0, 3.0, 1.0],
+        vec![0.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    let u = [1.0, 0.5, -1.0];
+    let v = [0.0, 2.0, 1.0];
+
+    let outer_product = (Matrix::try_from(u.iter().map(|&item| vec![item]).collect_vec()).unwrap()
+        * Matrix::try_from(vec![v.to_vec()]).unwrap())
+    .unwrap();
+    let expected_result = (a.clone() + outer_product).inverse().unwrap();
+
+    let result = Matrix::sherman_morrison(&a.inverse().unwrap(), &u, &v).unwrap();
+
+    assert_matrix_near(&result, &expected_result, 1e-10);
+}
+
+#[test]
+fn test_sherman_morrison_zero_denominator() {
+    // 1 + vᵀ A⁻¹ u = 1 - 1 = 0, so A + u vᵀ is singular.
+    let a_inv = Matrix::identity(2);
+
+    assert_eq!(
+        Matrix::sherman_morrison(&a_inv, &[1.0, 0.0], &[-1.0, 0.0]),
+        None
+    );
+}
+
+#[test]
+fn test_try_mul_matches_operator() {
+    let matrix1 = Matrix::try_from(vec![vec![2.0, 1.0], vec![0.0, 3.0], vec![-1.0, 2.0]]).unwrap();
+    let matrix2 = Matrix::try_from(vec![vec![-1.0, 0.0, 1.0], vec![2.0, 3.0, -1.0]]).unwrap();
+
+    assert_eq!(matrix1.try_mul(&matrix2).ok(), matrix1 * matrix2);
+}
+
+#[test]
+fn test_try_mul_dimension_mismatch_message() {
+    let matrix1: Matrix = Matrix::zero(Dimensions::Rectangle {
+        rows: 2,
+        columns: 3,
+    });
+    let matrix2 = Matrix::zero(Dimensions::Rectangle {
+        rows: 4,
+        columns: 5,
+    });
+
+    match matrix1.try_mul(&matrix2) {
+        Err(ErrorKind::DimensionsIncorrct(message)) => {
+            assert!(message.contains("2x3"), "{message}");
+            assert!(message.contains("4x5"), "{message}");
+        }
+        result => panic!("expected a dimension error, got {result:?}"),
+    }
+}
+
+#[test]
+fn test_from_diag_k_off_diagonals() {
+    let superdiagonal = vec![
+        vec![0.0, 1.0, 0.0],
+        vec![0.0, 0.0, 2.0],
+        vec![0.0, 0.0, 0.0],
+    ];
+    let subdiagonal = vec![
+        vec![0.0, 0.0, 0.0],
+        vec![0.0, 0.0, 0.0],
+        vec![5.0, 0.0, 0.0],
+    ];
+
+    assert_eq!(
+        Matrix::from_diag_k(vec![1.0, 2.0], 1, 3),
+        Matrix::try_from(superdiagonal).ok()
+    );
+    assert_eq!(
+        Matrix::from_diag_k(vec![5.0], -2, 3),
+        Matrix::try_from(subdiagonal).ok()
+    );
+    assert_eq!(
+        Matrix::from_diag_k(vec![1.0, 2.0, 3.0], 0, 3),
+        Some(Matrix::diagonal(vec![1.0, 2.0, 3.0]))
+    );
+}
+
+#[test]
+fn test_from_diag_k_length_mismatch() {
+    assert_eq!(Matrix::from_diag_k(vec![1.0, 2.0, 3.0], 1, 3), None);
+    assert_eq!(F64Matrix::from_diag_k(vec![], 4, 3), None);
+}
+
+#[test]
+fn test_rls_estimator_converges_to_linear_model() {
+    let true_parameters = [2.0, -1.0, 0.5];
+    let mut estimator = RlsEstimator::new(3, 1e6);
+
+    for sample in 0..50 {
+        let t = sample as f64;
+        let x = [1.0, (t * 0.3).sin(), t / 10.0];
+        let y = x
+            .iter()
+            .zip(true_parameters.iter())
+            .map(|(a, b)| a * b)
+            .sum();
+
+        assert!(estimator.update(&x, y));
+    }
+
+    let expected_result =
+        Matrix::try_from(true_parameters.iter().map(|&item| vec![item]).collect_vec()).unwrap();
+
+    assert_matrix_near(&estimator.parameters(), &expected_result, 1e-6);
+}
+
+#[test]
+fn test_rls_estimator_rejects_mismatched_observation() {
+    let mut estimator = RlsEstimator::new(2, 100.0);
+
+    assert!(!estimator.update(&[1.0, 2.0, 3.0], 1.0));
+    assert_eq!(
+        estimator.parameters(),
+        Matrix::zero(Dimensions::Rectangle {
+            rows: 2,
+            columns: 1
+        })
+    );
+}
+
+#[test]
+fn test_checked_add_and_sub_controlled_matrices() {
+    let matrix1 = Matrix::try_from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    let matrix2 = Matrix::try_from(vec![vec![5.0, 6.0], vec![7.0, 8.0]]).unwrap();
+
+    let sum = Matrix::try_from(vec![vec![6.0, 8.0], vec![10.0, 12.0]]).unwrap();
+    let difference = Matrix::constant(Dimensions::Square(2), -4.0);
+
+    assert_eq!(matrix1.checked_add(&matrix2).unwrap(), sum);
+    assert_eq!(matrix1.checked_sub(&matrix2).unwrap(), difference);
+    assert_eq!(matrix1.clone() - matrix2, difference);
+}
+
+#[test]
+fn test_checked_add_and_sub_dimension_mismatch() {
+    let matrix1: Matrix = Matrix::identity(2);
+    let matrix2 = Matrix::zero(Dimensions::Rectangle {
+        rows: 2,
+        columns: 3,
+    });
+
+    assert!(matches!(
+        matrix1.checked_add(&matrix2),
+        Err(ErrorKind::DimensionsIncorrct(_))
+    ));
+    assert!(matches!(
+        matrix1.checked_sub(&matrix2),
+        Err(ErrorKind::DimensionsIncorrct(_))
+    ));
+}
+
+#[test]
+fn test_matrix_macro_matches_try_from() {
+    let square = matrix![[1.0, 2.0], [3.0, 4.0]];
+    let row = matrix![[1.0, 3.0, 5.0]];
+
+    assert_eq!(
+        square,
+        Matrix::try_from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap()
+    );
+    assert_eq!(row, Matrix::try_from(vec![vec![1.0, 3.0, 5.0]]).unwrap());
+    assert!(row.is_row());
+}
+
+#[test]
+#[should_panic(expected = "Row sizes should be equal.")]
+fn test_matrix_macro_ragged_rows() {
+    matrix![[1.0, 2.0], [3.0]];
+}
+
+#[test]
+fn test_block_diagonal_mixed_blocks() {
+    let blocks = vec![
+        matrix![[1.0, 2.0], [3.0, 4.0]],
+        matrix![[5.0, 6.0, 7.0]],
+        matrix![[8.0]],
+    ];
+
+    let expected_result = matrix![
+        [1.0, 2.0, 0.0, 0.0, 0.0, 0.0],
+        [3.0, 4.0, 0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 5.0, 6.0, 7.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0, 0.0, 8.0],
+    ];
+
+    assert_eq!(Matrix::block_diagonal(blocks), expected_result);
+}
+
+#[test]
+fn test_block_diagonal_empty() {
+    let matrix: Matrix = Matrix::block_diagonal(vec![]);
+
+    assert_eq!(matrix.dimensions, Dimensions::Square(0));
+    assert_eq!(matrix.dimensions.count(), 0);
+}
+
+#[test]
+fn test_kalman_filter_tracks_constant_velocity() {
+    let dt = 0.1;
+    let velocity = 2.0;
+
+    let f = matrix![[1.0, dt], [0.0, 1.0]];
+    let q = Matrix::scalar(1e-5, 2);
+    let h = matrix![[1.0, 0.0]];
+    let r = matrix![[0.04]];
+
+    let mut filter = KalmanFilter::new(matrix![[0.0], [0.0]], Matrix::scalar(10.0, 2));
+
+    for step in 1..=200 {
+        let position = velocity * dt * step as f64;
+        // Deterministic pseudo-noise keeps the test reproducible.
+        let noise = 0.2 * (step as f64 * 12.9898).sin();
+
+        assert!(filter.predict(&f, &q));
+        assert!(filter.update(&h, &r, &matrix![[position + noise]]));
+    }
+
+    let position = velocity * dt * 200.0;
+    assert!((filter.state.get(0, 0).unwrap() - position).abs() < 0.1);
+    assert!((filter.state.get(1, 0).unwrap() - velocity).abs() < 0.1);
+}
+
+#[test]
+fn test_kalman_filter_rejects_mismatched_model() {
+    let mut filter = KalmanFilter::new(matrix![[0.0], [0.0]], Matrix::identity(2));
+
+    assert!(!filter.predict(&Matrix::identity(3), &Matrix::identity(3)));
+    assert!(!filter.update(&matrix![[1.0, 0.0]], &Matrix::identity(2), &matrix![[1.0]]));
+    assert_eq!(filter.state, matrix![[0.0], [0.0]]);
+}
+
+#[test]
+fn test_hstack_and_vstack_controlled_matrices() {
+    let left = matrix![[1.0], [4.0]];
+    let right = matrix![[2.0, 3.0], [5.0, 6.0]];
+    let bottom = matrix![[7.0, 8.0, 9.0]];
+
+    let top = Matrix::hstack(&[left, right]).unwrap();
+    assert_eq!(top, matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(
+        Matrix::vstack(&[top, bottom]),
+        Some(matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+    );
+    assert_eq!(F64Matrix::hstack(&[]), None);
+}
+
+#[test]
+fn test_block_controlled_layout() {
+    let layout = vec![
+        vec![matrix![[1.0, 2.0], [3.0, 4.0]], matrix![[5.0], [6.0]]],
+        vec![matrix![[7.0, 8.0, 9.0]]],
+    ];
+
+    let expected_result = matrix![[1.0, 2.0, 5.0], [3.0, 4.0, 6.0], [7.0, 8.0, 9.0]];
+
+    assert_eq!(Matrix::block(layout), Some(expected_result));
+}
+
+#[test]
+fn test_block_mismatched_layout() {
+    let unequal_heights = vec![vec![F64Matrix::identity(2), Matrix::identity(1)]];
+    let unequal_widths = vec![vec![Matrix::identity(2)], vec![matrix![[1.0, 2.0, 3.0]]]];
+
+    assert_eq!(Matrix::block(unequal_heights), None);
+    assert_eq!(Matrix::block(unequal_widths), None);
+}
+
+#[test]
+fn test_banded_solve_tridiagonal_matches_dense() {
+    let matrix = matrix![
+        [4.0, -1.0, 0.0, 0.0, 0.0],
+        [-1.0, 4.0, -1.0, 0.0, 0.0],
+        [0.0, -1.0, 4.0, -1.0, 0.0],
+        [0.0, 0.0, -1.0, 4.0, -1.0],
+        [0.0, 0.0, 0.0, -1.0, 4.0],
+    ];
+    let b = matrix![[1.0, 0.0], [2.0, 1.0], [3.0, 0.0], [4.0, 1.0], [5.0, 0.0]];
+
+    let expected_result = (matrix.inverse().unwrap() * b.clone()).unwrap();
+
+    assert_matrix_near(
+        &matrix.banded_solve(&b, 1, 1).unwrap(),
+        &expected_result,
+        1e-10,
+    );
+}
+
+#[test]
+fn test_banded_solve_invalid_inputs() {
+    let matrix = matrix![[0.0, 1.0], [1.0, 0.0]];
+
+    assert_eq!(matrix.banded_solve(&matrix![[1.0], [1.0]], 1, 1), None);
+    assert_eq!(
+        Matrix::identity(2).banded_solve(&matrix![[1.0], [1.0], [1.0]], 1, 1),
+        None
+    );
+}
+
+#[test]
+fn test_as_slice_is_row_major() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    assert_eq!(matrix.as_slice(), matrix.rows().concat().as_slice());
+}
+
+#[test]
+fn test_into_vec_length() {
+    let matrix = Matrix::constant(
+        Dimensions::Rectangle {
+            rows: 3,
+            columns: 4,
+        },
+        2.0,
+    );
+    let count = matrix.dimensions.count();
+
+    assert_eq!(matrix.into_vec().len(), count);
+}
+
+#[test]
+fn test_thomas_solve_matches_dense() {
+    let sub = [1.0, -2.0, 0.5, 1.0];
+    let diag = [4.0, 5.0, 6.0, 3.0, 7.0];
+    let sup = [-1.0, 2.0, 1.0, -1.5];
+    let rhs = [1.0, -2.0, 3.0, 0.5, 4.0];
+
+    let matrix = Matrix::from_diag_k(sub.to_vec(), -1, 5).unwrap()
+        + Matrix::diagonal(diag.to_vec())
+        + Matrix::from_diag_k(sup.to_vec(), 1, 5).unwrap();
+    let b = Matrix::try_from(rhs.iter().map(|&item| vec![item]).collect_vec()).unwrap();
+    let expected_result = (matrix.inverse().unwrap() * b).unwrap();
+
+    let result = Matrix::thomas_solve(&sub, &diag, &sup, &rhs).unwrap();
+
+    for (i, item) in result.iter().enumerate() {
+        assert!((item - expected_result.get(i, 0).unwrap()).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_thomas_solve_invalid_inputs() {
+    assert_eq!(
+        Matrix::thomas_solve(&[1.0], &[1.0, 2.0], &[], &[1.0, 1.0]),
+        None
+    );
+    assert_eq!(
+        Matrix::thomas_solve(&[1.0], &[0.0, 2.0], &[1.0], &[1.0, 1.0]),
+        None
+    );
+}
+
+#[test]
+fn test_row_echelon_form_controlled_matrix() {
+    let matrix = matrix![[1.0, 2.0, 1.0], [2.0, 4.0, 0.0], [1.0, 2.0, 3.0]];
+
+    let expected_result = matrix![[2.0, 4.0, 0.0], [0.0, 0.0, 3.0], [0.0, 0.0, 0.0]];
+
+    assert_matrix_near(&matrix.row_echelon_form(), &expected_result, 1e-12);
+}
+
+#[test]
+fn test_row_echelon_form_rectangular_is_upper_triangular() {
+    let matrix = matrix![
+        [1.0, 3.0, 5.0, 7.0],
+        [2.0, 4.0, 6.0, 8.0],
+        [3.0, 7.0, 11.0, 2.0]
+    ];
+
+    let echelon = matrix.row_echelon_form();
+
+    for (i, row) in echelon.rows().iter().enumerate() {
+        assert!(row.iter().take(i).all(|item| item == &0.0));
+    }
+}
+
+#[test]
+fn test_view_reads_parent_elements() {
+    let matrix = matrix![
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0]
+    ];
+
+    let view: MatrixView = matrix.view(1..3, 1..3).unwrap();
+
+    assert_eq!(view.dimensions(), Dimensions::Square(2));
+    assert_eq!(view.get(0, 0), Some(&6.0));
+    assert_eq!(view.get(1, 1), Some(&11.0));
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(view.rows(), vec![&[6.0, 7.0][..], &[10.0, 11.0][..]]);
+}
+
+#[test]
+fn test_view_to_owned_matches_submatrix() {
+    let matrix = matrix![
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0]
+    ];
+
+    assert_eq!(
+        matrix.view(0..2, 1..4).unwrap().to_owned(),
+        matrix.submatrix(0..2, 1..4).unwrap()
+    );
+    assert!(matrix.view(0..4, 0..1).is_none());
+}
+
+#[test]
+fn test_properties_m_matrix() {
+    let matrix = matrix![[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]];
+
+    assert!(matrix.is_m_matrix());
+}
+
+#[test]
+fn test_properties_not_m_matrix() {
+    // The first has the right sign pattern but a negative inverse, the second positive off-diagonal entries.
+    let weak_diagonal = matrix![[1.0, -2.0], [-2.0, 1.0]];
+    let positive_off_diagonal = matrix![[2.0, 1.0], [1.0, 2.0]];
+
+    assert!(!weak_diagonal.is_m_matrix());
+    assert!(!positive_off_diagonal.is_m_matrix());
+}
+
+#[test]
+fn test_rref_controlled_matrix() {
+    let matrix = matrix![
+        [1.0, 2.0, -1.0, -4.0],
+        [2.0, 3.0, -1.0, -11.0],
+        [-2.0, 0.0, -3.0, 22.0]
+    ];
+
+    let expected_result = matrix![
+        [1.0, 0.0, 0.0, -8.0],
+        [0.0, 1.0, 0.0, 1.0],
+        [0.0, 0.0, 1.0, -2.0]
+    ];
+
+    assert_matrix_near(&matrix.rref(), &expected_result, 1e-12);
+}
+
+#[test]
+fn test_rref_rank_deficient_matrix() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 1.0, 1.0]];
+
+    let expected_result = matrix![[1.0, 0.0, -1.0], [0.0, 1.0, 2.0], [0.0, 0.0, 0.0]];
+
+    assert_matrix_near(&matrix.rref(), &expected_result, 1e-12);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_round_trip_preserves_positions() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    let converted = nalgebra::DMatrix::<f64>::from(matrix.clone());
+
+    assert_eq!(converted.shape(), (2, 3));
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(converted[(i, j)], *matrix.get(i, j).unwrap());
+        }
+    }
+    assert_eq!(Matrix::from(converted), matrix);
+}
+
+#[test]
+fn test_incomplete_cholesky_tridiagonal_is_exact() {
+    let matrix = matrix![
+        [4.0, -1.0, 0.0, 0.0],
+        [-1.0, 4.0, -1.0, 0.0],
+        [0.0, -1.0, 4.0, -1.0],
+        [0.0, 0.0, -1.0, 4.0]
+    ];
+
+    let factor = matrix.incomplete_cholesky(0.0).unwrap();
+
+    assert!(factor.is_lower_triangular());
+    assert_matrix_near(
+        &(factor.clone() * factor.transposed()).unwrap(),
+        &matrix,
+        1e-12,
+    );
+}
+
+#[test]
+fn test_incomplete_cholesky_preserves_sparsity() {
+    // 2D Laplacian on a 3x2 grid; the exact Cholesky factor would fill in the zeros inside the band.
+    let matrix = matrix![
+        [4.0, -1.0, 0.0, -1.0, 0.0, 0.0],
+        [-1.0, 4.0, -1.0, 0.0, -1.0, 0.0],
+        [0.0, -1.0, 4.0, 0.0, 0.0, -1.0],
+        [-1.0, 0.0, 0.0, 4.0, -1.0, 0.0],
+        [0.0, -1.0, 0.0, -1.0, 4.0, -1.0],
+        [0.0, 0.0, -1.0, 0.0, -1.0, 4.0]
+    ];
+
+    let factor = matrix.incomplete_cholesky(0.0).unwrap();
+    let product = (factor.clone() * factor.transposed()).unwrap();
+
+    for i in 0..6 {
+        for j in 0..=i {
+            let item = *matrix.get(i, j).unwrap();
+            if item == 0.0 {
+                assert_eq!(factor.get(i, j), Some(&0.0));
+            } else {
+                assert!((product.get(i, j).unwrap() - item).abs() < 1e-12);
+            }
+        }
+    }
+    assert_matrix_near(&product, &matrix, 0.5);
+}
+
+#[test]
+fn test_incomplete_cholesky_non_positive_pivot() {
+    let matrix = matrix![[1.0, 2.0], [2.0, 1.0]];
+
+    assert_eq!(matrix.incomplete_cholesky(0.0), None);
+}
+
+#[test]
+fn test_lu_decomposition_reconstructs_permuted_matrix() {
+    let matrix = matrix![[1.0, 3.0, 0.0], [2.0, 4.0, 5.0], [3.0, 7.0, 6.0]];
+
+    let (lower, upper, permutation) = matrix.lu_decomposition().unwrap();
+    let permuted = Matrix::try_from(
+        permutation
+            .iter()
+            .map(|&i| matrix.row(i).unwrap())
+            .collect_vec(),
+    )
+    .unwrap();
+
+    assert!(lower.is_lower_triangular());
+    assert!(upper.is_upper_triangular());
+    assert_matrix_near(&(lower * upper).unwrap(), &permuted, 1e-12);
+}
+
+#[test]
+fn test_solve_multiple_right_hand_sides() {
+    let matrix = matrix![[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]];
+    let b = matrix![[8.0, 1.0], [-11.0, 0.0], [-3.0, 0.0]];
+
+    let solution = matrix.solve(&b).unwrap();
+
+    assert_matrix_near(
+        &solution
+            .column(0)
+            .map(|column| {
+                Matrix::try_from(column.into_iter().map(|item| vec![item]).collect_vec()).unwrap()
+            })
+            .unwrap(),
+        &matrix![[2.0], [3.0], [-1.0]],
+        1e-12,
+    );
+    assert_matrix_near(&(matrix * solution).unwrap(), &b, 1e-12);
+}
+
+#[test]
+fn test_solve_invalid_systems() {
+    let singular = matrix![[1.0, 2.0], [2.0, 4.0]];
+    let rectangular = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    assert_eq!(singular.solve(&matrix![[1.0], [2.0]]), None);
+    assert_eq!(rectangular.solve(&matrix![[1.0], [2.0]]), None);
+    assert_eq!(Matrix::identity(2).solve(&matrix![[1.0]]), None);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_round_trip_preserves_order() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    let converted = ndarray::Array2::<f64>::from(matrix.clone());
+
+    assert_eq!(converted.dim(), (2, 3));
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(converted[[i, j]], *matrix.get(i, j).unwrap());
+        }
+    }
+    assert_eq!(
+        Matrix::from(converted.reversed_axes().reversed_axes()),
+        matrix
+    );
+}
+
+#[test]
+fn test_pcg_solve_converges_faster_than_cg() {
+    // Tridiagonal SPD matrix whose diagonal spans four orders of magnitude.
+    let size = 8;
+    let diagonal = (0..size)
+        .map(|i| 10f64.powf(i as f64 / 2.0) + 1.0)
+        .collect_vec();
+    let matrix = Matrix::diagonal(diagonal)
+        + Matrix::from_diag_k(vec![-0.5; size - 1], 1, size).unwrap()
+        + Matrix::from_diag_k(vec![-0.5; size - 1], -1, size).unwrap();
+    let b = Matrix::ones(Dimensions::from((size, 1)));
+
+    let preconditioner = matrix.incomplete_cholesky(0.0).unwrap();
+    let identity = Matrix::identity(size);
+
+    let solution = matrix.pcg_solve(&b, &preconditioner, 2, 1e-10).unwrap();
+
+    assert_matrix_near(&solution, &matrix.solve(&b).unwrap(), 1e-9);
+    assert_eq!(matrix.pcg_solve(&b, &identity, 2, 1e-10), None);
+    assert!(matrix.pcg_solve(&b, &identity, 50, 1e-10).is_some());
+}
+
+#[test]
+fn test_pcg_solve_dimension_mismatch() {
+    let matrix = Matrix::identity(3);
+
+    assert_eq!(
+        matrix.pcg_solve(&Matrix::ones(Dimensions::from((2, 1))), &matrix, 10, 1e-10),
+        None
+    );
+}
+
+#[test]
+fn test_qr_decomposition_reconstructs_matrix() {
+    let matrix = matrix![
+        [12.0, -51.0, 4.0],
+        [6.0, 167.0, -68.0],
+        [-4.0, 24.0, -41.0],
+        [1.0, 1.0, 1.0]
+    ];
+
+    let (q, r) = matrix.qr_decomposition().unwrap();
+
+    assert!(r.is_upper_triangular());
+    assert_matrix_near(
+        &(q.transposed() * q.clone()).unwrap(),
+        &Matrix::identity(3),
+        1e-12,
+    );
+    assert_matrix_near(&(q * r).unwrap(), &matrix, 1e-10);
+}
+
+#[test]
+fn test_lstsq_fits_line() {
+    // Noisy samples of y = 2x + 1.
+    let a = matrix![[0.0, 1.0], [1.0, 1.0], [2.0, 1.0], [3.0, 1.0]];
+    let b = matrix![[1.1], [2.9], [5.1], [6.9]];
+
+    let solution = a.lstsq(&b).unwrap();
+
+    let normal_equations = (a.transposed() * a.clone()).unwrap();
+    let expected_result = normal_equations
+        .solve(&(a.transposed() * b).unwrap())
+        .unwrap();
+
+    assert_eq!(solution.dimensions, Dimensions::from((2, 1)));
+    assert_matrix_near(&solution, &expected_result, 1e-10);
+}
+
+#[test]
+fn test_lstsq_rank_deficient() {
+    let a = matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+
+    assert_eq!(a.lstsq(&matrix![[1.0], [2.0], [3.0]]), None);
+    assert_eq!(a.lstsq(&matrix![[1.0], [2.0]]), None);
+}
+
+#[test]
+fn test_transpose_square_in_place() {
+    let mut matrix = Matrix::try_from(
+        (0..4)
+            .map(|i| (0..4).map(|j| (i * 4 + j) as f64).collect_vec())
+            .collect_vec(),
+    )
+    .unwrap();
+    let expected_result = Matrix::try_from(matrix.columns()).unwrap();
+    let buffer = matrix.as_slice().as_ptr();
+
+    matrix.transpose();
+
+    assert_eq!(matrix, expected_result);
+    assert_eq!(matrix.as_slice().as_ptr(), buffer);
+}
+
+#[test]
+fn test_gmres_nonsymmetric_matches_solve() {
+    let matrix = matrix![
+        [4.0, 1.0, 0.0, 2.0, 0.0],
+        [-1.0, 5.0, 1.0, 0.0, 0.5],
+        [0.0, -2.0, 6.0, 1.0, 0.0],
+        [1.0, 0.0, -1.0, 4.0, 1.0],
+        [0.0, 3.0, 0.0, -1.0, 7.0]
+    ];
+    let b = matrix![[1.0], [2.0], [3.0], [4.0], [5.0]];
+    let expected_result = matrix.solve(&b).unwrap();
+
+    assert_matrix_near(
+        &matrix.gmres(&b, 5, 5, 1e-12).unwrap(),
+        &expected_result,
+        1e-10,
+    );
+    assert_matrix_near(
+        &matrix.gmres(&b, 2, 200, 1e-12).unwrap(),
+        &expected_result,
+        1e-10,
+    );
+}
+
+#[test]
+fn test_gmres_not_converged() {
+    let matrix = Matrix::diagonal(vec![1.0, 2.0, 3.0, 4.0]);
+    let b = Matrix::ones(Dimensions::from((4, 1)));
+
+    assert_eq!(matrix.gmres(&b, 4, 2, 1e-12), None);
+    assert!(matrix.gmres(&b, 4, 4, 1e-12).is_some());
+}
+
+#[test]
+fn test_forward_substitute_controlled_system() {
+    let matrix = matrix![[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-1.0, 2.0, 4.0]];
+
+    let x = matrix.forward_substitute(&[2.0, 7.0, 15.0]).unwrap();
+
+    assert_eq!(x, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_forward_substitute_invalid_systems() {
+    let upper = matrix![[1.0, 2.0], [0.0, 1.0]];
+    let zero_diagonal = matrix![[1.0, 0.0], [2.0, 0.0]];
+
+    assert_eq!(upper.forward_substitute(&[1.0, 1.0]), None);
+    assert_eq!(zero_diagonal.forward_substitute(&[1.0, 1.0]), None);
+    assert_eq!(Matrix::identity(2).forward_substitute(&[1.0]), None);
+}
+
+#[test]
+fn test_frobenius_inner_controlled_matrices() {
+    let matrix1 = matrix![[1.0, 2.0], [3.0, 4.0]];
+    let matrix2 = matrix![[0.0, -1.0], [2.0, 0.5]];
+
+    assert_eq!(matrix1.frobenius_inner(&matrix2), Some(6.0));
+    assert_eq!(matrix1.frobenius_inner(&matrix![[1.0, 2.0]]), None);
+}
+
+#[test]
+fn test_frobenius_inner_with_itself_is_squared_norm() {
+    let matrix = matrix![[1.5, -2.0, 3.0], [0.25, 4.0, -6.0]];
+
+    let inner = matrix.frobenius_inner(&matrix).unwrap();
+
+    assert!((inner - matrix.frobenius_norm().powi(2)).abs() < 1e-12);
+}
+
+#[test]
+fn test_arnoldi_relation_holds() {
+    let matrix = matrix![
+        [4.0, 1.0, 0.0, 2.0, 0.0],
+        [-1.0, 5.0, 1.0, 0.0, 0.5],
+        [0.0, -2.0, 6.0, 1.0, 0.0],
+        [1.0, 0.0, -1.0, 4.0, 1.0],
+        [0.0, 3.0, 0.0, -1.0, 7.0]
+    ];
+    let steps = 3;
+
+    let (q, h) = matrix.arnoldi(&[1.0, 0.0, 1.0, 0.0, 1.0], steps).unwrap();
+    let q_k = q.submatrix(0..5, 0..steps).unwrap();
+
+    assert_eq!(q.dimensions, Dimensions::from((5, steps + 1)));
+    assert_eq!(h.dimensions, Dimensions::from((steps + 1, steps)));
+    assert_matrix_near(
+        &(q.transposed() * q.clone()).unwrap(),
+        &Matrix::identity(steps + 1),
+        1e-12,
+    );
+    assert_matrix_near(&(matrix * q_k).unwrap(), &(q * h).unwrap(), 1e-10);
+}
+
+#[test]
+fn test_arnoldi_breakdown() {
+    // An eigenvector spans an invariant subspace, so the second basis vector vanishes.
+    let matrix = Matrix::diagonal(vec![1.0, 2.0, 3.0]);
+
+    assert!(matrix.arnoldi(&[1.0, 0.0, 0.0], 1).is_none());
+    assert!(matrix.arnoldi(&[0.0, 0.0, 0.0], 1).is_none());
+}
+
+#[test]
+fn test_backward_substitute_controlled_system() {
+    let matrix = matrix![[2.0, 1.0, -1.0], [0.0, 3.0, 2.0], [0.0, 0.0, 4.0]];
+
+    let x = matrix.backward_substitute(&[1.0, 12.0, 12.0]).unwrap();
+
+    assert_eq!(x, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_backward_substitute_invalid_systems() {
+    let lower = matrix![[1.0, 0.0], [2.0, 1.0]];
+    let zero_diagonal = matrix![[0.0, 1.0], [0.0, 1.0]];
+
+    assert_eq!(lower.backward_substitute(&[1.0, 1.0]), None);
+    assert_eq!(zero_diagonal.backward_substitute(&[1.0, 1.0]), None);
+    assert_eq!(
+        Matrix::identity(2).backward_substitute(&[1.0, 1.0, 1.0]),
+        None
+    );
+}
+
+#[test]
+fn test_lanczos_estimates_extreme_eigenvalues() {
+    // The eigenvalues of this tridiagonal matrix are 2 - 2cos(kπ/5), k = 1..=4.
+    let matrix = matrix![
+        [2.0, -1.0, 0.0, 0.0],
+        [-1.0, 2.0, -1.0, 0.0],
+        [0.0, -1.0, 2.0, -1.0],
+        [0.0, 0.0, -1.0, 2.0]
+    ];
+    let smallest = 2.0 - 2.0 * (std::f64::consts::PI / 5.0).cos();
+    let largest = 2.0 - 2.0 * (4.0 * std::f64::consts::PI / 5.0).cos();
+
+    let (q, alpha, beta) = matrix.lanczos(&[1.0, 2.0, 3.0, 4.0], 4).unwrap();
+    let tridiagonal = Matrix::from_diag_k(alpha, 0, 4).unwrap()
+        + Matrix::from_diag_k(beta.clone(), 1, 4).unwrap()
+        + Matrix::from_diag_k(beta, -1, 4).unwrap();
+
+    assert_matrix_near(
+        &(q.transposed() * q.clone()).unwrap(),
+        &Matrix::identity(4),
+        1e-12,
+    );
+    assert_matrix_near(
+        &(q.transposed() * (matrix * q).unwrap()).unwrap(),
+        &tridiagonal,
+        1e-10,
+    );
+    for eigenvalue in [smallest, largest] {
+        let shifted = tridiagonal.clone() - Matrix::scalar(eigenvalue, 4);
+        assert!(shifted.determinant().unwrap().abs() < 1e-10);
+    }
+}
+
+#[test]
+fn test_lanczos_invalid_inputs() {
+    let matrix = Matrix::diagonal(vec![1.0, 2.0, 3.0]);
+
+    assert!(matrix.lanczos(&[1.0, 1.0, 1.0], 0).is_none());
+    assert!(matrix.lanczos(&[1.0, 1.0], 2).is_none());
+    assert!(matrix.lanczos(&[0.0, 0.0, 0.0], 2).is_none());
+    assert!(matrix.lanczos(&[1.0, 0.0, 0.0], 2).is_none());
+}
+
+#[test]
+fn test_gram_schmidt_orthonormal_columns() {
+    let matrix = matrix![
+        [1.0, 1.0, 0.0],
+        [1.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0],
+        [1.0, 1.0, 1.0]
+    ];
+
+    let q = matrix.gram_schmidt().unwrap();
+    let projection = (q.clone() * q.transposed()).unwrap();
+
+    assert_eq!(q.dimensions, matrix.dimensions);
+    assert_matrix_near(&(q.transposed() * q).unwrap(), &Matrix::identity(3), 1e-12);
+    // Projecting onto the new basis leaves the original columns unchanged.
+    assert_matrix_near(&(projection * matrix.clone()).unwrap(), &matrix, 1e-12);
+}
+
+#[test]
+fn test_gram_schmidt_rank_deficient() {
+    let matrix = matrix![[1.0, 2.0, 0.0], [2.0, 4.0, 1.0], [3.0, 6.0, 1.0]];
+
+    assert_eq!(matrix.gram_schmidt(), None);
+}
+
+#[test]
+fn test_spectral_radius_known_dominant_eigenvalue() {
+    // Eigenvalues 4 and -1.
+    let matrix = matrix![[1.0, 2.0], [3.0, 2.0]];
+    let negative_dominant = Matrix::diagonal(vec![1.0, -4.0, 2.0]);
+
+    assert!((matrix.spectral_radius(1000, 1e-12).unwrap() - 4.0).abs() < 1e-9);
+    assert!((negative_dominant.spectral_radius(1000, 1e-12).unwrap() - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_spectral_radius_invalid_inputs() {
+    let rectangle = Matrix::zero(Dimensions::from((2, 3)));
+    let slow = Matrix::diagonal(vec![1.0, 0.999]);
+
+    assert_eq!(rectangle.spectral_radius(100, 1e-10), None);
+    assert_eq!(
+        Matrix::zero(Dimensions::Square(2)).spectral_radius(100, 1e-10),
+        None
+    );
+    assert_eq!(slow.spectral_radius(3, 1e-15), None);
+}
+
+#[test]
+fn test_extreme_eigenvalues_symmetric_matrix() {
+    // The eigenvalues of this tridiagonal matrix are 2 - 2cos(kπ/11), k = 1..=10.
+    let size = 10;
+    let matrix = Matrix::from_diag_k(vec![2.0; size], 0, size).unwrap()
+        + Matrix::from_diag_k(vec![-1.0; size - 1], 1, size).unwrap()
+        + Matrix::from_diag_k(vec![-1.0; size - 1], -1, size).unwrap();
+    let eigenvalue = |k: f64| 2.0 - 2.0 * (k * std::f64::consts::PI / 11.0).cos();
+    let (smallest, largest) = (eigenvalue(1.0), eigenvalue(10.0));
+
+    let (exact_smallest, exact_largest) = matrix.extreme_eigenvalues(size).unwrap();
+    let (short_smallest, short_largest) = matrix.extreme_eigenvalues(6).unwrap();
+
+    assert!((exact_smallest - smallest).abs() < 1e-8);
+    assert!((exact_largest - largest).abs() < 1e-8);
+    assert!(short_smallest >= smallest - 1e-10 && short_smallest - smallest < 0.1);
+    assert!(short_largest <= largest + 1e-10 && largest - short_largest < 0.2);
+}
+
+#[test]
+fn test_extreme_eigenvalues_invariant_krylov_subspace() {
+    let close = |(smallest, largest): (f64, f64), (lower, upper): (f64, f64)| {
+        (smallest - lower).abs() < 1e-8 && (largest - upper).abs() < 1e-8
+    };
+
+    assert!(close(
+        Matrix::identity(3).extreme_eigenvalues(3).unwrap(),
+        (1.0, 1.0)
+    ));
+    assert!(close(
+        Matrix::diagonal(vec![1.0, 1.0, 2.0])
+            .extreme_eigenvalues(3)
+            .unwrap(),
+        (1.0, 2.0)
+    ));
+    assert!(close(
+        Matrix::scalar(5.0, 4).extreme_eigenvalues(2).unwrap(),
+        (5.0, 5.0)
+    ));
+    // The start vector (1, 2) is an eigenvector for 5, so the smallest eigenvalue 0 is only found
+    // after restarting.
+    assert!(close(
+        matrix![[1.0, 2.0], [2.0, 4.0]]
+            .extreme_eigenvalues(2)
+            .unwrap(),
+        (0.0, 5.0)
+    ));
+    // The public Lanczos process still reports the breakdown.
+    assert!(Matrix::identity(3).lanczos(&[1.0, 2.0, 3.0], 3).is_none());
+}
+
+#[test]
+fn test_extreme_eigenvalues_invalid_inputs() {
+    assert_eq!(Matrix::identity(3).extreme_eigenvalues(0), None);
+    assert_eq!(
+        Matrix::zero(Dimensions::from((2, 3))).extreme_eigenvalues(2),
+        None
+    );
+}
+
+#[test]
+fn test_normalize_rows() {
+    let matrix = matrix![[3.0, 4.0], [0.0, 0.0], [-2.0, 0.0]];
+
+    assert_eq!(
+        matrix.normalize_rows(),
+        matrix![[0.6, 0.8], [0.0, 0.0], [-1.0, 0.0]]
+    );
+}
+
+#[test]
+fn test_condition_number() {
+    let well_conditioned = matrix![[2.0, 1.0], [1.0, 3.0]];
+    let near_singular = matrix![[1.0, 1.0], [1.0, 1.0 + 1e-8]];
+    // Symmetric positive definite, so the singular values are the eigenvalues (5 ± √5) / 2.
+    let expected = (5.0 + 5.0_f64.sqrt()) / (5.0 - 5.0_f64.sqrt());
+
+    assert!((well_conditioned.condition_number().unwrap() - expected).abs() < 1e-12);
+    assert!(near_singular.condition_number().unwrap() > 1e8);
+    assert_eq!(Matrix::identity(3).condition_number(), Some(1.0));
+    assert_eq!(
+        matrix![[1.0, 2.0], [2.0, 4.0]].condition_number(),
+        Some(f64::INFINITY)
+    );
+    assert_eq!(
+        Matrix::zero(Dimensions::from((2, 3))).condition_number(),
+        None
+    );
+}
+
+#[test]
+fn test_singular_values() {
+    let matrix = matrix![[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]];
+    let rotation = Matrix::rotation_2d(0.7);
+
+    let values = matrix.singular_values();
+    let scaled = (rotation * Matrix::diagonal(vec![2.0, -7.0]))
+        .unwrap()
+        .singular_values();
+
+    assert_eq!(values.len(), 2);
+    assert!((values[0] - 5.0).abs() < 1e-12 && (values[1] - 3.0).abs() < 1e-12);
+    assert_eq!(matrix.transposed().singular_values().len(), 2);
+    assert!((scaled[0] - 7.0).abs() < 1e-12 && (scaled[1] - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_to_fixed_string_aligned_lines() {
+    let matrix = matrix![
+        [1.0, -123.456, 0.0],
+        [-7.25, 3.0, 99.999],
+        [0.001, 10.0, -0.5]
+    ];
+
+    let formatted = matrix.to_fixed_string(9, 3);
+    let lines = formatted.lines().collect_vec();
+
+    assert!(formatted.ends_with('\n'));
+    assert_eq!(lines.len(), 3);
+    assert!(lines.iter().all(|line| line.len() == 3 * 9 + 2));
+    assert_eq!(lines[0], "    1.000  -123.456     0.000");
+}
+
+#[test]
+fn test_normalize_columns() {
+    let matrix = matrix![[3.0, 0.0, -2.0], [4.0, 0.0, 0.0]];
+
+    assert_eq!(
+        matrix.normalize_columns(),
+        matrix![[0.6, 0.0, -1.0], [0.8, 0.0, 0.0]]
+    );
+}
+
+#[test]
+fn test_into_iterator_rows() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    let borrowed = (&matrix).into_iter().map(|row| row.to_vec()).collect_vec();
+    let expected = matrix.rows();
+    let mut owned = Vec::new();
+    for row in matrix {
+        owned.push(row);
+    }
+
+    assert_eq!(borrowed, expected);
+    assert_eq!(owned, expected);
+}
+
+#[test]
+fn test_grayscale_round_trip() {
+    let pixels = (0..=255).step_by(17).map(|pixel| pixel as u8).collect_vec();
+
+    let image = Matrix::from_grayscale(&pixels, 4, 4).unwrap();
+
+    assert_eq!(image.dimensions, Dimensions::Square(4));
+    assert!(
+        image
+            .as_slice()
+            .iter()
+            .all(|item| (0.0..=1.0).contains(item))
+    );
+    assert_eq!(image.to_grayscale(), pixels);
+}
+
+#[test]
+fn test_grayscale_clamping_and_size_mismatch() {
+    let matrix = matrix![[-0.5, 0.5, 1.5]];
+
+    assert_eq!(matrix.to_grayscale(), vec![0, 128, 255]);
+    assert!(matches!(
+        Matrix::from_grayscale(&[0, 1, 2], 2, 2),
+        Err(ErrorKind::DimensionsIncorrct(_))
+    ));
+}
+
+#[test]
+fn test_map_and_apply() {
+    let mut matrix = matrix![[-1.0, 2.0], [3.0, -4.0]];
+    let relu = |item: f64| item.max(0.0);
+
+    let mapped = matrix.map(relu);
+    matrix.apply(relu);
+
+    assert_eq!(mapped, matrix![[0.0, 2.0], [3.0, 0.0]]);
+    assert_eq!(matrix, mapped);
+}
+
+#[test]
+fn test_flip_vertical() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    let flipped = matrix.flip_vertical();
+
+    assert_eq!(flipped.dimensions, matrix.dimensions);
+    assert_eq!(flipped.as_slice(), &[4.0, 5.0, 6.0, 1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_flip_horizontal() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    let flipped = matrix.flip_horizontal();
+
+    assert_eq!(flipped.dimensions, matrix.dimensions);
+    assert_eq!(flipped.as_slice(), &[3.0, 2.0, 1.0, 6.0, 5.0, 4.0]);
+}
+
+#[test]
+fn test_integer_product_rounds_cleanly() {
+    let first = matrix![[1.0, -2.0, 3.0], [4.0, 0.0, -1.0]] * 0.1;
+    let second = matrix![[2.0, 1.0], [0.0, -3.0], [5.0, 7.0]] * 10.0;
+
+    let product = (first * second).unwrap();
+    let rounded = product.round_to_integers();
+
+    assert!(product.is_integer_valued(1e-9));
+    assert!(!matrix![[0.5, 1.0]].is_integer_valued(1e-9));
+    assert_eq!(rounded, matrix![[17.0, 28.0], [3.0, -3.0]]);
+    assert!(rounded.is_integer_valued(0.0));
+}
+
+#[test]
+fn test_set_row() {
+    let mut matrix = Matrix::zero(Dimensions::from((2, 3)));
+
+    assert!(matrix.set_row(1, &[4.0, 5.0, 6.0]));
+    assert!(matrix.set_row(0, &[1.0, 2.0, 3.0]));
+    assert!(!matrix.set_row(2, &[0.0, 0.0, 0.0]));
+    assert!(!matrix.set_row(0, &[0.0, 0.0]));
+    assert_eq!(matrix, matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+}
+
+#[test]
+fn test_rotate_90_cw() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    assert_eq!(
+        matrix.rotate_90_cw(),
+        matrix![[4.0, 1.0], [5.0, 2.0], [6.0, 3.0]]
+    );
+}
+
+#[test]
+fn test_rotate_90_ccw() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+    assert_eq!(
+        matrix.rotate_90_ccw(),
+        matrix![[3.0, 6.0], [2.0, 5.0], [1.0, 4.0]]
+    );
+    assert_eq!(matrix.rotate_90_ccw().rotate_90_cw(), matrix);
+}
+
+#[test]
+fn test_reduce_rows_by_gcd() {
+    let matrix = matrix![
+        [6.0, -9.0, 12.0],
+        [0.0, 0.0, 0.0],
+        [2.0, 3.0, 5.0],
+        [0.0, -4.0, 8.0]
+    ];
+
+    assert_eq!(
+        matrix.reduce_rows_by_gcd(),
+        matrix![
+            [2.0, -3.0, 4.0],
+            [0.0, 0.0, 0.0],
+            [2.0, 3.0, 5.0],
+            [0.0, -1.0, 2.0]
+        ]
+    );
+    assert_eq!(
+        matrix![[-1e19, 2.0], [-4.0, 6.0]].reduce_rows_by_gcd(),
+        matrix![[-1e19, 2.0], [-2.0, 3.0]]
+    );
+}
+
+#[test]
+fn test_set_column() {
+    let mut matrix = Matrix::zero(Dimensions::from((2, 3)));
+
+    assert!(matrix.set_column(0, &[1.0, 4.0]));
+    assert!(matrix.set_column(2, &[3.0, 6.0]));
+    assert!(!matrix.set_column(3, &[0.0, 0.0]));
+    assert!(!matrix.set_column(1, &[0.0, 0.0, 0.0]));
+    assert_eq!(matrix, matrix![[1.0, 0.0, 3.0], [4.0, 0.0, 6.0]]);
+}
+
+#[test]
+fn test_multiplication_50x50_matches_naive_product() {
+    let size = 50;
+    let first = Matrix::try_from(
+        (0..size)
+            .map(|i| {
+                (0..size)
+                    .map(|j| ((i * 7 + j * 3) % 11) as f64 - 5.0)
+                    .collect()
+            })
+            .collect_vec(),
+    )
+    .unwrap();
+    let second = Matrix::try_from(
+        (0..size)
+            .map(|i| {
+                (0..size)
+                    .map(|j| ((i * 5 + j * 2) % 13) as f64 - 6.0)
+                    .collect()
+            })
+            .collect_vec(),
+    )
+    .unwrap();
+
+    let product = (first.clone() * second.clone()).unwrap();
+
+    for i in 0..size {
+        for j in 0..size {
+            let naive: f64 = (0..size)
+                .map(|k| first.get(i, k).unwrap() * second.get(k, j).unwrap())
+                .sum();
+            assert_eq!(product.get(i, j), Some(&naive));
+        }
+    }
+}
+
+#[test]
+fn test_smith_normal_form() {
+    let matrix = matrix![[2.0, 4.0, 4.0], [-6.0, 6.0, 12.0], [10.0, -4.0, -16.0]];
+
+    let (u, d, v) = matrix.smith_normal_form().unwrap();
+
+    assert_eq!(d, Matrix::diagonal(vec![2.0, 6.0, 12.0]));
+    assert_eq!(((u.clone() * matrix).unwrap() * v.clone()).unwrap(), d);
+    assert!(u.is_integer_valued(0.0) && v.is_integer_valued(0.0));
+    assert_eq!(u.determinant().unwrap().abs().round(), 1.0);
+    assert_eq!(v.determinant().unwrap().abs().round(), 1.0);
+}
+
+#[test]
+fn test_smith_normal_form_rectangular_and_invalid() {
+    let matrix = matrix![[4.0, 6.0, 10.0], [8.0, 12.0, 20.0]];
+
+    let (u, d, v) = matrix.smith_normal_form().unwrap();
+
+    assert_eq!(d, matrix![[2.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+    assert_eq!(((u * matrix).unwrap() * v).unwrap(), d);
+    assert_eq!(matrix![[0.5, 1.0]].smith_normal_form(), None);
+}
+
+#[test]
+fn test_insert_row() {
+    let mut matrix = matrix![[1.0, 2.0], [5.0, 6.0]];
+
+    assert!(matrix.insert_row(1, vec![3.0, 4.0]).is_ok());
+    assert_eq!(matrix.dimensions, Dimensions::from((3, 2)));
+    assert!(matrix.insert_row(3, vec![7.0, 8.0]).is_ok());
+    assert!(matrix.insert_row(5, vec![0.0, 0.0]).is_err());
+    assert!(matrix.insert_row(0, vec![0.0]).is_err());
+    assert_eq!(
+        matrix,
+        matrix![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]
+    );
+}
+
+#[test]
+fn test_trace_of_product() {
+    let first = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let second = matrix![[7.0, -1.0], [0.5, 2.0], [-3.0, 4.0]];
+
+    assert_eq!(
+        first.trace_of_product(&second),
+        (first.clone() * second.clone()).unwrap().trace()
+    );
+    assert_eq!(
+        second.trace_of_product(&first),
+        (second * first.clone()).unwrap().trace()
+    );
+    assert_eq!(first.trace_of_product(&first), None);
+    assert_eq!(first.trace(), None);
+    assert_eq!(Matrix::identity(4).trace(), Some(4.0));
+}
+
+#[test]
+fn test_hermite_normal_form() {
+    let matrix = matrix![
+        [2.0, 3.0, 6.0, 2.0],
+        [5.0, 6.0, 1.0, 6.0],
+        [8.0, 3.0, 1.0, 1.0]
+    ];
+    let triangular = matrix![
+        [3.0, 3.0, 1.0, 4.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 19.0, 16.0],
+        [0.0, 0.0, 0.0, 3.0]
+    ];
+
+    assert_eq!(
+        matrix.hermite_normal_form(),
+        Some(matrix![
+            [1.0, 0.0, 50.0, -11.0],
+            [0.0, 3.0, 28.0, -2.0],
+            [0.0, 0.0, 61.0, -13.0]
+        ])
+    );
+    assert_eq!(
+        triangular.hermite_normal_form(),
+        Some(matrix![
+            [3.0, 0.0, 1.0, 1.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 19.0, 1.0],
+            [0.0, 0.0, 0.0, 3.0]
+        ])
+    );
+    assert_eq!(matrix![[1.5]].hermite_normal_form(), None);
+}
+
+#[test]
+fn test_insert_column() {
+    let mut matrix = matrix![[1.0, 3.0], [4.0, 6.0]];
+
+    assert!(matrix.insert_column(1, vec![2.0, 5.0]).is_ok());
+    assert_eq!(matrix.dimensions, Dimensions::from((2, 3)));
+    assert!(matrix.insert_column(3, vec![-1.0, -2.0]).is_ok());
+    assert!(matrix.insert_column(5, vec![0.0, 0.0]).is_err());
+    assert!(matrix.insert_column(0, vec![0.0]).is_err());
+    assert_eq!(
+        matrix,
+        matrix![[1.0, 2.0, 3.0, -1.0], [4.0, 5.0, 6.0, -2.0]]
+    );
+}
+
+#[test]
+fn test_companion_degree_3_layout() {
+    // x³ - 6x² + 11x - 6 = (x - 1)(x - 2)(x - 3)
+    let companion = Matrix::companion(&[-6.0, 11.0, -6.0]).unwrap();
+
+    assert_eq!(
+        companion,
+        matrix![[0.0, 0.0, 6.0], [1.0, 0.0, -11.0], [0.0, 1.0, 6.0]]
+    );
+    for root in [1.0, 2.0, 3.0] {
+        let shifted = companion.clone() - Matrix::scalar(root, 3);
+        assert!(shifted.determinant().unwrap().abs() < 1e-10);
+    }
+    assert!(Matrix::companion(&[]).is_err());
+}
+
+#[test]
+fn test_format_as_fractions() {
+    let matrix = matrix![[0.5, 1.0 / 3.0, -2.0], [0.333_333_333_3, 0.1, 22.0 / 7.0]];
+
+    assert_eq!(
+        matrix.format_as_fractions(100),
+        "1/2 1/3 -2\n1/3 1/10 22/7\n"
+    );
+    assert_eq!(
+        matrix![[std::f64::consts::PI]].format_as_fractions(10),
+        "22/7\n"
+    );
+    assert_eq!(
+        matrix![[0.5, 0.25]].format_as_fractions(u64::MAX),
+        "1/2 1/4\n"
+    );
+    assert_eq!(
+        matrix![[1e20, -0.5]].format_as_fractions(10),
+        "100000000000000000000 -1/2\n"
+    );
+}
+
+#[test]
+fn test_delete_row() {
+    let mut matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+    assert!(!matrix.delete_row(3));
+    assert!(matrix.delete_row(1));
+    assert_eq!(
+        matrix.dimensions,
+        Dimensions::Rectangle {
+            rows: 2,
+            columns: 3
+        }
+    );
+    assert_eq!(matrix, matrix![[1.0, 2.0, 3.0], [7.0, 8.0, 9.0]]);
+}
+
+#[test]
+fn test_normalized_rows_and_columns_have_unit_norm() {
+    let matrix = matrix![
+        [1.0, -2.0, 0.0],
+        [0.0, 0.0, 0.0],
+        [3.5, 0.25, 0.0],
+        [-1.0, 7.0, 0.0]
+    ];
+    let norm = |vector: &[f64]| vector.iter().map(|item| item * item).sum::<f64>().sqrt();
+
+    let rows = matrix.normalize_rows().rows();
+    let columns = matrix.normalize_columns().columns();
+
+    assert_eq!(rows[1], vec![0.0; 3]);
+    assert_eq!(columns[2], vec![0.0; 4]);
+    for row in [&rows[0], &rows[2], &rows[3]] {
+        assert!((norm(row) - 1.0).abs() < 1e-12);
+    }
+    for column in &columns[..2] {
+        assert!((norm(column) - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_to_rational_pairs_exact_recovery() {
+    let matrix = matrix![
+        [1.0 / 2.0, -2.0 / 3.0],
+        [5.0, 7.0 / 16.0],
+        [-13.0 / 11.0, 0.0]
+    ];
+
+    assert_eq!(
+        matrix.to_rational_pairs(1000),
+        vec![(1, 2), (-2, 3), (5, 1), (7, 16), (-13, 11), (0, 1)]
+    );
+    assert_eq!(
+        matrix![[f64::NAN, f64::NEG_INFINITY]].to_rational_pairs(10),
+        vec![(0, 0), (-1, 0)]
+    );
+    assert_eq!(
+        matrix![[0.5, 0.25]].to_rational_pairs(u64::MAX),
+        vec![(1, 2), (1, 4)]
+    );
+    assert_eq!(
+        matrix![[1e20, -1e20]].to_rational_pairs(10),
+        vec![(i64::MAX, 1), (i64::MIN, 1)]
+    );
+}
+
+#[test]
+fn test_delete_column() {
+    let mut matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+    assert!(!matrix.delete_column(3));
+    assert!(matrix.delete_column(0));
+    assert_eq!(
+        matrix.dimensions,
+        Dimensions::Rectangle {
+            rows: 3,
+            columns: 2
+        }
+    );
+    assert_eq!(matrix, matrix![[2.0, 3.0], [5.0, 6.0], [8.0, 9.0]]);
+}
+
+#[test]
+fn test_projection_symmetric_and_idempotent() {
+    let matrix = matrix![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [0.5, -1.0]];
+
+    let projection = matrix.projection().unwrap();
+
+    assert_eq!(projection.dimensions, Dimensions::Square(4));
+    assert_matrix_near(&projection.transposed(), &projection, 1e-12);
+    assert!(projection.is_idempotent(1e-12));
+    assert_matrix_near(&(projection * matrix.clone()).unwrap(), &matrix, 1e-12);
+    assert_eq!(matrix![[1.0, 2.0], [2.0, 4.0]].projection(), None);
+}
+
+#[test]
+fn test_transform_points_2d_translation_and_rotation() {
+    let translation = matrix![[1.0, 0.0, 2.0], [0.0, 1.0, -1.0], [0.0, 0.0, 1.0]];
+    let rotation = Matrix::block_diagonal(vec![
+        Matrix::rotation_2d(std::f64::consts::FRAC_PI_2),
+        Matrix::identity(1),
+    ]);
+    let points = [(0.0, 0.0), (1.0, 2.0), (-3.0, 0.5)];
+
+    let translated = translation.transform_points_2d(&points).unwrap();
+    let rotated = rotation.transform_points_2d(&points).unwrap();
+
+    assert_eq!(translated, vec![(2.0, -1.0), (3.0, 1.0), (-1.0, -0.5)]);
+    for (&(x, y), (expected_x, expected_y)) in
+        rotated.iter().zip([(0.0, 0.0), (-2.0, 1.0), (-0.5, -3.0)])
+    {
+        assert!((x - expected_x).abs() < 1e-12 && (y - expected_y).abs() < 1e-12);
+    }
+    assert_eq!(Matrix::identity(2).transform_points_2d(&points), None);
+}
+
+#[test]
+fn test_anti_transpose() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let square = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let mut in_place = square.clone();
+
+    in_place.anti_transpose_inplace();
+
+    assert_eq!(
+        matrix.anti_transpose(),
+        matrix![[6.0, 3.0], [5.0, 2.0], [4.0, 1.0]]
+    );
+    assert_eq!(
+        in_place,
+        matrix![[9.0, 6.0, 3.0], [8.0, 5.0, 2.0], [7.0, 4.0, 1.0]]
+    );
+    assert_eq!(in_place, square.rotate_90_ccw().flip_horizontal());
+    assert_eq!(matrix.anti_transpose().anti_transpose(), matrix);
+}
+
+#[test]
+fn test_dimensions_constructors() {
+    assert_eq!(Dimensions::square(3), Dimensions::Square(3));
+    assert_eq!(
+        Dimensions::rectangle(2, 5),
+        Dimensions::Rectangle {
+            rows: 2,
+            columns: 5
+        }
+    );
+    assert_eq!(Dimensions::rectangle(4, 4), Dimensions::square(4));
+    assert_eq!(
+        Matrix::with_value(Dimensions::rectangle(2, 3), 1.5),
+        Matrix::constant(Dimensions::from((2, 3)), 1.5)
+    );
+}
+
+#[test]
+fn test_compose_translation_then_rotation() {
+    let translation = matrix![[1.0, 0.0, 2.0], [0.0, 1.0, -1.0], [0.0, 0.0, 1.0]];
+    let rotation = Matrix::block_diagonal(vec![Matrix::rotation_2d(0.3), Matrix::identity(1)]);
+    let points = [(0.0, 0.0), (1.0, 2.0), (-3.0, 0.5)];
+
+    let composed = Matrix::compose(&[translation.clone(), rotation.clone()]).unwrap();
+    let sequential = rotation
+        .transform_points_2d(&translation.transform_points_2d(&points).unwrap())
+        .unwrap();
+
+    for ((x, y), (expected_x, expected_y)) in composed
+        .transform_points_2d(&points)
+        .unwrap()
+        .into_iter()
+        .zip(sequential)
+    {
+        assert!((x - expected_x).abs() < 1e-12 && (y - expected_y).abs() < 1e-12);
+    }
+    assert_eq!(Matrix::compose(&[]), None);
+    assert_eq!(Matrix::compose(&[translation, Matrix::identity(2)]), None);
+}
+
+#[test]
+fn test_readme_example() {
+    let collection = vec![
+        vec![1.0, 3.0, 5.0],
+        vec![2.0, 4.0, 6.0],
+        vec![3.0, 7.0, 11.0],
+    ];
+    let matrix = Matrix::try_from(collection).unwrap();
+
+    let sum = matrix.clone() + Matrix::with_value(Dimensions::square(3), 2.0);
+
+    assert!(matrix.determinant().unwrap().abs() < 1e-12);
+    assert_eq!(
+        sum,
+        matrix![[3.0, 5.0, 7.0], [4.0, 6.0, 8.0], [5.0, 9.0, 13.0]]
+    );
+}
+
+#[test]
+fn test_determinants_batch_matches_sequential() {
+    let matrices = (1..=20)
+        .map(|n| {
+            let size = n % 5 + 1;
+            Matrix::try_from(
+                (0..size)
+                    .map(|i| {
+                        (0..size)
+                            .map(|j| ((i * n + j * 3 + 1) % 7) as f64)
+                            .collect()
+                    })
+                    .collect_vec(),
+            )
+            .unwrap()
+        })
+        .chain([Matrix::zero(Dimensions::from((2, 3)))])
+        .collect_vec();
+
+    let batch = Matrix::determinants_batch(&matrices);
+
+    assert_eq!(
+        batch,
+        matrices.iter().map(Matrix::determinant).collect_vec()
+    );
+    assert_eq!(batch.last(), Some(&None));
+}
+
+#[test]
+fn test_infinity_norm() {
+    let matrix = matrix![[1.0, -7.0, 2.0], [-4.0, 3.0, -5.0]];
+
+    assert_eq!(matrix.infinity_norm(), 12.0);
+    assert_eq!(Matrix::identity(3).infinity_norm(), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "To add matrices they should be of the the same dimensions.")]
+fn test_add_operator_dimension_mismatch_panics() {
+    let _ = F64Matrix::identity(2) + Matrix::identity(3);
+}
+
+#[test]
+#[should_panic(expected = "To subtract matrices they should be of the same dimensions.")]
+fn test_sub_operator_dimension_mismatch_panics() {
+    let _ = F64Matrix::identity(2) - Matrix::identity(3);
+}
+
+#[test]
+fn test_checked_variants_report_operator_failures() {
+    let (small, large) = (Matrix::identity(2), Matrix::identity(3));
+
+    assert!(small.checked_add(&large).is_err());
+    assert!(small.checked_sub(&large).is_err());
+    assert!(small.try_mul(&large).is_err());
+    assert!(matches!(
+        small.checked_div(0.0),
+        Err(ErrorKind::DividedByZero)
+    ));
+    assert_eq!(small.checked_div(2.0).unwrap(), Matrix::scalar(0.5, 2));
+    assert!((small / 0.0).as_slice().iter().any(|item| item.is_nan()));
+}
+
+#[test]
+fn test_mul_into_matches_operator_and_reuses_output() {
+    let first = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let second = matrix![[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]];
+    let mut out = Matrix::zero(Dimensions::Square(2));
+    let allocation = out.as_slice().as_ptr();
+
+    first.mul_into(&second, &mut out).unwrap();
+
+    assert_eq!(out, (first.clone() * second.clone()).unwrap());
+    assert_eq!(out.as_slice().as_ptr(), allocation);
+}
+
+#[test]
+fn test_mul_into_dimension_mismatch() {
+    let first = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let mut out = Matrix::zero(Dimensions::Square(2));
+    let mut wrong_out = Matrix::zero(Dimensions::Square(3));
+
+    assert!(first.mul_into(&first, &mut out).is_err());
+    assert!(first.mul_into(&first.transposed(), &mut wrong_out).is_err());
+    assert_eq!(wrong_out, Matrix::zero(Dimensions::Square(3)));
+}
+
+#[test]
+fn test_one_norm() {
+    let matrix = matrix![[1.0, -7.0, 2.0], [-4.0, 3.0, -5.0]];
+
+    assert_eq!(matrix.one_norm(), 10.0);
+    assert_eq!(matrix.one_norm(), matrix.transposed().infinity_norm());
+    assert_eq!(Matrix::identity(3).one_norm(), 1.0);
+}
+
+#[test]
+fn test_default_empty_matrix() {
+    let mut matrix = matrix![[1.0, 2.0]];
+
+    let taken = std::mem::take(&mut matrix);
+
+    assert_eq!(taken, matrix![[1.0, 2.0]]);
+    assert_eq!(matrix, Matrix::default());
+    assert_eq!(F64Matrix::default(), Matrix::zero(0.into()));
+    assert_eq!(matrix.dimensions.count(), 0);
+    assert!(matrix.rows().is_empty() && matrix.columns().is_empty());
+    assert_eq!((&matrix).into_iter().count(), 0);
+    assert_eq!(Matrix::try_from(Vec::<Vec<f64>>::new()).unwrap(), matrix);
+    let without_columns = F64Matrix::zero(Dimensions::from((2, 0)));
+    assert_eq!(without_columns.rows(), vec![Vec::<f64>::new(); 2]);
+    assert_eq!(
+        (&without_columns).into_iter().collect_vec(),
+        vec![&[] as &[f64]; 2]
+    );
+}
+
+#[test]
+fn test_add_into_and_sub_into_match_operators() {
+    let first = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let second = matrix![[0.5, -1.0, 2.0], [3.0, 0.0, -6.0]];
+    let mut sum = Matrix::zero(first.dimensions);
+    let mut difference = Matrix::zero(first.dimensions);
+
+    first.add_into(&second, &mut sum).unwrap();
+    first.sub_into(&second, &mut difference).unwrap();
+
+    assert_eq!(sum, first.clone() + second.clone());
+    assert_eq!(difference, first - second);
+}
+
+#[test]
+fn test_add_into_and_sub_into_dimension_mismatch() {
+    let matrix: Matrix = Matrix::identity(2);
+    let mut out = Matrix::zero(Dimensions::Square(3));
+
+    assert!(matrix.add_into(&matrix, &mut out).is_err());
+    assert!(matrix.sub_into(&Matrix::identity(3), &mut out).is_err());
+    assert_eq!(out, Matrix::zero(Dimensions::Square(3)));
+}
+
+#[test]
+fn test_abs() {
+    let mut matrix = matrix![[-1.5, 2.0], [0.0, -4.0]];
+    let expected = matrix![[1.5, 2.0], [0.0, 4.0]];
+
+    assert_eq!(matrix.abs(), expected);
+    matrix.abs_inplace();
+    assert_eq!(matrix, expected);
+}
+
+#[test]
+fn test_diagonal_values_and_set_diagonal() {
+    let mut matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+    assert_eq!(matrix.diagonal_values(), Some(vec![1.0, 5.0, 9.0]));
+    assert!(matrix.set_diagonal(&[-1.0, -5.0, -9.0]));
+    assert!(!matrix.set_diagonal(&[0.0, 0.0]));
+    assert_eq!(
+        matrix,
+        matrix![[-1.0, 2.0, 3.0], [4.0, -5.0, 6.0], [7.0, 8.0, -9.0]]
+    );
+    assert_eq!(matrix![[1.0, 2.0]].diagonal_values(), None);
+    assert!(!matrix![[1.0, 2.0]].set_diagonal(&[0.0]));
+}
+
+#[test]
+fn test_transpose_into_matches_transposed() {
+    let matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let mut out = Matrix::zero(Dimensions::from((3, 2)));
+    let allocation = out.as_slice().as_ptr();
+
+    matrix.transpose_into(&mut out).unwrap();
+
+    assert_eq!(out, matrix.transposed());
+    assert_eq!(out.as_slice().as_ptr(), allocation);
+    assert!(
+        matrix
+            .transpose_into(&mut Matrix::zero(Dimensions::from((2, 3))))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_transcendental_elementwise() {
+    let matrix = matrix![[4.0, 0.0], [1.0, 2.25]];
+
+    assert_eq!(matrix.sqrt_elementwise(), matrix![[2.0, 0.0], [1.0, 1.5]]);
+    assert_matrix_near(&matrix.ln_elementwise().exp_elementwise(), &matrix, 1e-12);
+    assert_eq!(
+        matrix![[0.0, 1.0]].exp_elementwise(),
+        matrix![[1.0, std::f64::consts::E]]
+    );
+}
+
+#[test]
+fn test_transcendental_elementwise_invalid_inputs() {
+    let matrix = matrix![[-1.0, 0.0]];
+
+    let roots = matrix.sqrt_elementwise();
+    let logarithms = matrix.ln_elementwise();
+
+    assert!(roots.get(0, 0).unwrap().is_nan());
+    assert!(logarithms.get(0, 0).unwrap().is_nan());
+    assert_eq!(logarithms.get(0, 1), Some(&f64::NEG_INFINITY));
+    assert_eq!(
+        matrix![[1000.0]].exp_elementwise(),
+        matrix![[f64::INFINITY]]
+    );
+}
+
+#[test]
+fn test_fill_diagonal() {
+    let mut matrix = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let mut rectangle = Matrix::zero(Dimensions::from((2, 3)));
+
+    assert!(matrix.fill_diagonal(5.0));
+    assert!(!rectangle.fill_diagonal(5.0));
+    assert_eq!(
+        matrix,
+        matrix![[5.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 5.0]]
+    );
+    assert_eq!(rectangle, Matrix::zero(Dimensions::from((2, 3))));
+}
+
+#[test]
+fn test_axpy_into() {
+    let x = matrix![[1.0, -2.0], [0.5, 4.0]];
+    let y = matrix![[3.0, 1.0], [-1.0, 0.0]];
+    let mut out = Matrix::zero(Dimensions::Square(2));
+
+    Matrix::axpy_into(2.5, &x, &y, &mut out).unwrap();
+
+    assert_eq!(out, x.clone() * 2.5 + y.clone());
+    assert!(Matrix::axpy_into(1.0, &x, &Matrix::identity(3), &mut out).is_err());
+    assert!(Matrix::axpy_into(1.0, &x, &y, &mut Matrix::identity(3)).is_err());
+}
+
+#[test]
+fn test_pow_elementwise() {
+    let matrix = matrix![[1.0, 2.0], [3.0, 4.0]];
+
+    assert_eq!(
+        matrix.pow_elementwise(2.0),
+        matrix![[1.0, 4.0], [9.0, 16.0]]
+    );
+    assert_eq!(matrix.pow_elementwise(0.5), matrix.sqrt_elementwise());
+    assert_ne!(matrix.pow_elementwise(2.0), matrix.pow(2).unwrap());
+}
+
+#[test]
+fn test_direct_sum() {
+    let first = matrix![[1.0, 2.0], [3.0, 4.0]];
+    let second = matrix![[5.0]];
+
+    let sum = first.direct_sum(&second);
+
+    assert_eq!(sum.dimensions, Dimensions::Square(3));
+    assert_eq!(
+        sum,
+        matrix![[1.0, 2.0, 0.0], [3.0, 4.0, 0.0], [0.0, 0.0, 5.0]]
+    );
+    assert_eq!(
+        matrix![[1.0, 2.0]]
+            .direct_sum(&matrix![[3.0], [4.0]])
+            .dimensions,
+        Dimensions::from((3, 3))
+    );
+}
+
+#[test]
+fn test_split_rows_mut() {
+    let mut matrix = matrix![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+
+    let (top, bottom) = matrix.split_rows_mut(1).unwrap();
+    std::thread::scope(|scope| {
+        scope.spawn(|| top.iter_mut().for_each(|item| *item *= 10.0));
+        scope.spawn(|| bottom.iter_mut().for_each(|item| *item = -*item));
+    });
+
+    assert_eq!(matrix, matrix![[10.0, 20.0], [-3.0, -4.0], [-5.0, -6.0]]);
+    assert_eq!(
+        matrix
+            .split_rows_mut(3)
+            .map(|(top, bottom)| (top.len(), bottom.len())),
+        Some((6, 0))
+    );
+    assert!(matrix.split_rows_mut(4).is_none());
+}
+
+#[test]
+fn test_rounding_elementwise() {
+    let matrix = matrix![[1.5, -1.5, 2.3], [-2.7, 0.0, 4.0]];
+
+    assert_eq!(
+        matrix.round_elementwise(),
+        matrix![[2.0, -2.0, 2.0], [-3.0, 0.0, 4.0]]
+    );
+    assert_eq!(
+        matrix.floor_elementwise(),
+        matrix![[1.0, -2.0, 2.0], [-3.0, 0.0, 4.0]]
+    );
+    assert_eq!(
+        matrix.ceil_elementwise(),
+        matrix![[2.0, -1.0, 3.0], [-2.0, 0.0, 4.0]]
+    );
+}
+
+#[test]
+fn test_schur_complement_split_at_1() {
+    let matrix = matrix![[2.0, 1.0, 0.0], [4.0, 3.0, 1.0], [2.0, 1.0, 5.0]];
+
+    // D - C A⁻¹ B = [[3, 1], [1, 5]] - [[4], [2]] (1/2) [[1, 0]]
+    assert_eq!(
+        matrix.schur_complement(1),
+        Some(matrix![[1.0, 1.0], [0.0, 5.0]])
+    );
+    assert_eq!(matrix.schur_complement(0), None);
+    assert_eq!(matrix.schur_complement(3), None);
+    assert_eq!(matrix![[0.0, 1.0], [1.0, 0.0]].schur_complement(1), None);
+}
+
+#[test]
+fn test_chunks_rows_mut() {
+    let mut matrix = Matrix::zero(Dimensions::from((5, 2)));
+
+    for (index, chunk) in matrix.chunks_rows_mut(2).enumerate() {
+        chunk.iter_mut().for_each(|item| *item = index as f64);
+    }
+
+    assert_eq!(
+        matrix,
+        matrix![[0.0, 0.0], [0.0, 0.0], [1.0, 1.0], [1.0, 1.0], [2.0, 2.0]]
+    );
+    assert_eq!(matrix.chunks_rows_mut(2).count(), 3);
+}
+
+#[test]
+fn test_clamp_elementwise() {
+    let mut matrix = matrix![[-2.0, 0.5], [1.5, 0.0]];
+
+    assert_eq!(
+        matrix.clamp_elementwise(0.0, 1.0),
+        matrix![[0.0, 0.5], [1.0, 0.0]]
+    );
+    assert_eq!(matrix.clamp_min(0.0), matrix![[0.0, 0.5], [1.5, 0.0]]);
+    assert_eq!(matrix.clamp_max(0.25), matrix![[-2.0, 0.25], [0.25, 0.0]]);
+    matrix.clamp_inplace(-1.0, 1.0);
+    assert_eq!(matrix, matrix![[-1.0, 0.5], [1.0, 0.0]]);
+}
+
+#[test]
+fn test_pseudo_inverse_tall_matrix() {
+    let matrix = matrix![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [0.5, -1.0]];
+
+    let pseudo_inverse = matrix.pseudo_inverse().unwrap();
+
+    assert_eq!(pseudo_inverse.dimensions, Dimensions::from((2, 4)));
+    assert_matrix_near(
+        &(pseudo_inverse.clone() * matrix.clone()).unwrap(),
+        &Matrix::identity(2),
+        1e-12,
+    );
+    assert_matrix_near(
+        &(matrix.clone() * pseudo_inverse).unwrap(),
+        &matrix.projection().unwrap(),
+        1e-12,
+    );
+}
+
+#[test]
+fn test_pseudo_inverse_wide_and_rank_deficient() {
+    let wide = matrix![[1.0, 2.0, 0.0], [0.0, 1.0, 3.0]];
+
+    let pseudo_inverse = wide.pseudo_inverse().unwrap();
+
+    assert_matrix_near(
+        &(wide * pseudo_inverse).unwrap(),
+        &Matrix::identity(2),
+        1e-12,
+    );
+    assert_eq!(
+        matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]].pseudo_inverse(),
+        None
+    );
+}
+
+#[test]
+fn test_sign_checks() {
+    let positive = matrix![[0.5, 1.0], [2.0, 3.0]];
+    let non_negative = matrix![[0.0, 1.0], [2.0, 3.0]];
+    let mixed = matrix![[-1.0, 1.0], [2.0, 3.0]];
+
+    assert!(positive.is_strictly_positive());
+    assert!(positive.is_non_negative());
+    assert!(!non_negative.is_strictly_positive());
+    assert!(non_negative.is_non_negative());
+    assert!(!mixed.is_non_negative());
+    assert!(!mixed.is_non_positive());
+
+    assert!((-positive.clone()).is_strictly_negative());
+    assert!((-non_negative).is_non_positive());
+    assert!(positive.all(|item| item < 4.0));
+    assert!(!positive.all(|item| item > 1.0));
+}
+
+#[test]
+fn test_is_similar() {
+    let a = matrix![[2.0, 1.0, 0.0], [0.0, 3.0, 1.0], [1.0, 0.0, 4.0]];
+    let p = matrix![[1.0, 2.0, 0.0], [0.0, 1.0, 1.0], [1.0, 0.0, 3.0]];
+
+    let conjugate = ((p.inverse().unwrap() * a.clone()).unwrap() * p).unwrap();
+
+    assert!(a.is_similar(&conjugate, 1e-9));
+    assert!(conjugate.is_similar(&a, 1e-9));
+    assert!(!a.is_similar(&Matrix::identity(3), 1e-9));
+    assert!(!a.is_similar(&Matrix::identity(2), 1e-9));
+    assert!(!matrix![[1.0, 2.0]].is_similar(&matrix![[1.0, 2.0]], 1e-9));
+}
+
+#[test]
+fn test_integer_matrix() {
+    let matrix: Matrix<i64> = matrix![[1, 2], [3, 4]];
+
+    let product = (matrix.clone() * Matrix::identity(2)).unwrap();
+    assert_eq!(product, matrix);
+    assert_eq!(
+        (matrix.clone() * matrix.transposed()).unwrap(),
+        matrix![[5, 11], [11, 25]]
+    );
+    assert_eq!(matrix.trace(), Some(5));
+    assert_eq!(
+        -matrix.clone() + matrix,
+        Matrix::zero(Dimensions::Square(2))
+    );
+}
+
+#[test]
+fn test_f32_matrix_matches_f64_alias() {
+    let single: Matrix<f32> = matrix![[0.5, 1.5], [2.0, -1.0]];
+    let double: F64Matrix = matrix![[0.5, 1.5], [2.0, -1.0]];
+
+    assert_eq!(
+        single.pow(3).unwrap().as_slice(),
+        double
+            .pow(3)
+            .unwrap()
+            .as_slice()
+            .iter()
+            .map(|&item| item as f32)
+            .collect_vec()
+    );
+}
+
+#[test]
+fn test_cholesky() {
+    let matrix = matrix![[4.0, 2.0, 0.0], [2.0, 5.0, 1.0], [0.0, 1.0, 3.0]];
+
+    let factor = matrix.cholesky().unwrap();
+
+    assert!(factor.is_lower_triangular());
+    assert_matrix_near(
+        &(factor.clone() * factor.transposed()).unwrap(),
+        &matrix,
+        1e-12,
+    );
+    assert_eq!(matrix![[1.0, 2.0], [2.0, 1.0]].cholesky(), None);
+    assert_eq!(matrix![[1.0, 2.0]].cholesky(), None);
+}
+
+#[test]
+fn test_is_positive_definite() {
+    let spd = matrix![[4.0, 2.0, 0.0], [2.0, 5.0, 1.0], [0.0, 1.0, 3.0]];
+    let indefinite = matrix![[1.0, 2.0], [2.0, 1.0]];
+    let not_symmetric = matrix![[2.0, 1.0], [0.0, 2.0]];
+
+    assert!(spd.is_positive_definite(1e-12));
+    assert!(!indefinite.is_positive_definite(1e-12));
+    assert!(!not_symmetric.is_positive_definite(1e-12));
+    assert!(!Matrix::zero(Dimensions::Square(2)).is_positive_definite(1e-12));
+
+    // Singular and positive semidefinite; the last pivot is only positive because of rounding.
+    let v = matrix![[0.1], [0.7]];
+    let semidefinite = (v.clone() * v.transposed()).unwrap();
+    assert!(semidefinite.cholesky().is_some());
+    assert!(!semidefinite.is_positive_definite(1e-9));
+}
+
+#[test]
+fn test_comparison_masks() {
+    let matrix = matrix![[0.2, 0.5, 0.9], [0.5, -1.0, 0.50000001]];
+
+    assert_eq!(
+        matrix.ge_mask(0.5),
+        matrix![[0.0, 1.0, 1.0], [1.0, 0.0, 1.0]]
+    );
+    assert_eq!(
+        matrix.gt_mask(0.5),
+        matrix![[0.0, 0.0, 1.0], [0.0, 0.0, 1.0]]
+    );
+    assert_eq!(
+        matrix.le_mask(0.5),
+        matrix![[1.0, 1.0, 0.0], [1.0, 1.0, 0.0]]
+    );
+    assert_eq!(
+        matrix.lt_mask(0.5),
+        matrix![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+    );
+    assert_eq!(
+        matrix.eq_mask(0.5, 1e-6),
+        matrix![[0.0, 1.0, 0.0], [1.0, 0.0, 1.0]]
+    );
+}
+
+#[test]
+fn test_strassen_mul_matches_naive_product() {
+    let integer_matrix = |rows: usize, columns: usize, seed: usize| {
+        Matrix::try_from(
+            (0..rows)
+                .map(|i| {
+                    (0..columns)
+                        .map(|j| ((i * 7 + j * 3 + seed) % 11) as f64 - 5.0)
+                        .collect()
+                })
+                .collect_vec(),
+        )
+        .unwrap()
+    };
+    // Padded to 130 × 132 and 132 × 130, then split into 65 × 66 and 66 × 65 quadrants that are
+    // padded again.
+    let a = integer_matrix(130, 131, 1);
+    let b = integer_matrix(131, 129, 4);
+
+    let product = a.strassen_mul(&b).unwrap();
+
+    assert_eq!(product.dimensions, Dimensions::from((130, 129)));
+    assert_eq!(product, (a.clone() * b).unwrap());
+
+    // A skinny product is computed directly instead of being padded to a large square.
+    let row = integer_matrix(1, 3000, 2);
+    let column = integer_matrix(3000, 1, 5);
+    assert_eq!(
+        row.strassen_mul(&column),
+        Some((row.clone() * column).unwrap())
+    );
+    assert_eq!(a.strassen_mul(&a), None);
+    assert_eq!(
+        matrix![[1.0, 2.0], [3.0, 4.0]].strassen_mul(&Matrix::identity(2)),
+        Some(matrix![[1.0, 2.0], [3.0, 4.0]])
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_par_mul_matches_sequential_product() {
+    let size = 70;
+    let a = Matrix::try_from(
+        (0..size)
+            .map(|i| {
+                (0..size)
+                    .map(|j| ((i * 7 + j * 3) % 11) as f64 - 5.0)
+                    .collect()
+            })
+            .collect_vec(),
+    )
+    .unwrap();
+    let b = a.transposed().map(|item| item * 0.5);
+
+    assert_eq!(a.par_mul(&b), a.clone() * b);
+    assert_eq!(
+        matrix![[1.0, 2.0]].par_mul(&matrix![[1.0], [2.0]]),
+        Some(matrix![[5.0]])
+    );
+    assert_eq!(matrix![[1.0, 2.0]].par_mul(&matrix![[1.0, 2.0]]), None);
+    assert_eq!(
+        Matrix::zero(Dimensions::from((2, 0))).par_mul(&Matrix::zero(Dimensions::from((0, 3)))),
+        Some(Matrix::zero(Dimensions::from((2, 3))))
+    );
+}
+
+#[cfg(feature = "complex")]
+#[test]
+fn test_complex_hermitian_adjoint() {
+    use crate::ComplexMatrix;
+    use num_complex::Complex64;
+
+    let matrix = ComplexMatrix::try_from(vec![
+        vec![Complex64::new(2.0, 0.0), Complex64::new(1.0, -1.0)],
+        vec![Complex64::new(1.0, 1.0), Complex64::new(3.0, 0.0)],
+    ])
+    .unwrap();
+
+    assert!(matrix.is_hermitian());
+    assert_eq!(matrix.adjoint(), matrix);
+    assert_ne!(matrix.transposed(), matrix);
+}
+
+#[cfg(feature = "complex")]
+#[test]
+fn test_complex_adjoint_and_multiplication() {
+    use crate::ComplexMatrix;
+    use num_complex::Complex64;
+
+    let matrix = ComplexMatrix::try_from(vec![
+        vec![Complex64::new(1.0, 2.0), Complex64::new(0.0, 1.0)],
+        vec![Complex64::new(3.0, 0.0), Complex64::new(4.0, -1.0)],
+    ])
+    .unwrap();
+
+    let expected_adjoint = ComplexMatrix::try_from(vec![
+        vec![Complex64::new(1.0, -2.0), Complex64::new(3.0, 0.0)],
+        vec![Complex64::new(0.0, -1.0), Complex64::new(4.0, 1.0)],
+    ])
+    .unwrap();
+
+    assert!(!matrix.is_hermitian());
+    assert_eq!(matrix.adjoint(), expected_adjoint);
+
+    let gram = (matrix.clone() * matrix.adjoint()).unwrap();
+    assert!(gram.is_hermitian());
+    assert_eq!(*gram.get(0, 0).unwrap(), Complex64::new(6.0, 0.0));
+    assert_eq!(
+        (matrix.clone() * ComplexMatrix::identity(2)).unwrap(),
+        matrix
+    );
+}
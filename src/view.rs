@@ -0,0 +1,69 @@
+use std::ops;
+
+use crate::{Dimensions, Matrix};
+
+/// An immutable view into a rectangular block of a [`Matrix`] that borrows the parent's
+/// buffer instead of copying it.
+///
+/// Created with [`Matrix::view`].
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a> {
+    buffer: &'a [f64],
+    row_offset: usize,
+    column_offset: usize,
+    /// Distance in the parent buffer between vertically adjacent elements.
+    stride: usize,
+    dimensions: Dimensions,
+}
+
+impl<'a> MatrixView<'a> {
+    pub(crate) fn new(
+        matrix: &'a Matrix,
+        rows: ops::Range<usize>,
+        columns: ops::Range<usize>,
+    ) -> Self {
+        Self {
+            buffer: &matrix.buffer,
+            row_offset: rows.start,
+            column_offset: columns.start,
+            stride: matrix.dimensions.columns(),
+            dimensions: Dimensions::from((rows.len(), columns.len())),
+        }
+    }
+
+    /// Returns the dimensions of the viewed block.
+    pub fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    /// Returns an option containing a reference to the element at row `i` and column `j` of the view.
+    ///
+    /// Returns `None` if indices are out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a f64> {
+        if i >= self.dimensions.rows() || j >= self.dimensions.columns() {
+            return None;
+        }
+
+        let index = (self.row_offset + i) * self.stride + self.column_offset + j;
+        self.buffer.get(index)
+    }
+
+    /// Returns the rows of the view as a vector of slices into the parent buffer.
+    pub fn rows(&self) -> Vec<&'a [f64]> {
+        let width = self.dimensions.columns();
+        (0..self.dimensions.rows())
+            .map(|i| {
+                let start = (self.row_offset + i) * self.stride + self.column_offset;
+                &self.buffer[start..start + width]
+            })
+            .collect()
+    }
+
+    /// Copies the viewed elements into a new matrix.
+    pub fn to_owned(&self) -> Matrix {
+        Matrix {
+            buffer: self.rows().concat(),
+            dimensions: self.dimensions,
+        }
+    }
+}